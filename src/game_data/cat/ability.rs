@@ -1,11 +1,13 @@
 //! Deals with cat/enemy abilities.
 
 use super::raw::stats::CombinedCatData;
+use crate::game_data::enemy::raw::stats::CombinedEnemyData;
+use serde::Serialize;
 use strum::EnumIter;
 
 type Percent = u8;
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default, Serialize)]
 /// Possible type of wave attack.
 pub enum WaveType {
     /// Normal wave.
@@ -15,7 +17,7 @@ pub enum WaveType {
     MiniWave,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default, Serialize)]
 /// Wave ability.
 pub struct Wave {
     /// Type of wave.
@@ -42,7 +44,7 @@ impl Wave {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default, Serialize)]
 /// Possible type of surge attack.
 pub enum SurgeType {
     /// Normal surge.
@@ -52,7 +54,7 @@ pub enum SurgeType {
     MiniSurge,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Default, Serialize)]
 /// Surge ability.
 pub struct Surge {
     /// Type of surge.
@@ -85,7 +87,7 @@ impl Surge {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, EnumIter)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, EnumIter, Serialize)]
 /// Cat or enemy ability.
 pub enum Ability {
     /// Strong against.
@@ -729,6 +731,152 @@ impl Ability {
     }
 }
 
+impl Ability {
+    /// Get the enemy's abilities as a vec. This only includes abilities that
+    /// both cats and enemies can have.
+    pub fn get_all_enemy_abilities(combined: &CombinedEnemyData) -> Vec<Ability> {
+        let (fixed, variable) = combined;
+        let mut abilities = vec![];
+
+        if chance(fixed.kb_chance) {
+            abilities.push(Self::Knockback {
+                chance: fixed.kb_chance,
+            });
+        }
+
+        if chance(fixed.freeze_chance) {
+            abilities.push(Self::Freeze {
+                chance: fixed.freeze_chance,
+                duration: fixed.freeze_duration,
+            });
+        }
+
+        if chance(fixed.slow_chance) {
+            abilities.push(Self::Slow {
+                chance: fixed.slow_chance,
+                duration: fixed.slow_duration,
+            });
+        }
+
+        if chance(fixed.crit_chance) {
+            abilities.push(Self::Crit {
+                chance: fixed.crit_chance,
+            });
+        }
+
+        if bool(fixed.has_base_destroyer).unwrap() {
+            abilities.push(Self::BaseDestroyer);
+        }
+
+        if chance(fixed.wave_chance) {
+            let wtype = match variable.is_mini_wave {
+                0 => WaveType::Wave,
+                1 => WaveType::MiniWave,
+                x => panic!("Mini wave flag should be 0 or 1, got {x}"),
+            };
+            abilities.push(Self::Wave(Wave {
+                wtype,
+                chance: fixed.wave_chance,
+                level: fixed.wave_level,
+            }));
+        }
+
+        if chance(fixed.weaken_chance) {
+            abilities.push(Self::Weaken {
+                chance: fixed.weaken_chance,
+                duration: fixed.weaken_duration,
+                multiplier: fixed.weaken_multiplier,
+            });
+        }
+
+        if fixed.strengthen_hp > 0 {
+            abilities.push(Self::Strengthen {
+                hp: fixed.strengthen_hp,
+                multiplier: fixed.strengthen_multiplier,
+            });
+        }
+
+        if chance(fixed.survives_chance) {
+            abilities.push(Self::Survives {
+                chance: fixed.survives_chance,
+            });
+        }
+
+        if bool(fixed.immune_wave).unwrap() {
+            abilities.push(Self::ImmuneToWave);
+        }
+
+        if bool(fixed.has_wave_blocker).unwrap() {
+            abilities.push(Self::WaveBlocker);
+        }
+
+        if bool(fixed.immune_kb).unwrap() {
+            abilities.push(Self::ImmuneToKB);
+        }
+
+        if bool(fixed.immune_freeze).unwrap() {
+            abilities.push(Self::ImmuneToFreeze);
+        }
+
+        if bool(fixed.immune_slow).unwrap() {
+            abilities.push(Self::ImmuneToSlow);
+        }
+
+        if bool(fixed.immune_weaken).unwrap() {
+            abilities.push(Self::ImmuneToWeaken);
+        }
+
+        if chance(variable.savage_blow_chance) {
+            abilities.push(Self::SavageBlow {
+                chance: variable.savage_blow_chance,
+                damage: variable.savage_blow_percent,
+            });
+        }
+
+        if chance(variable.dodge_chance) {
+            abilities.push(Self::Dodge {
+                chance: variable.dodge_chance,
+                duration: variable.dodge_duration,
+            });
+        }
+
+        if chance(variable.surge_chance) {
+            let stype = match variable.is_mini_surge {
+                0 => SurgeType::Surge,
+                1 => SurgeType::MiniSurge,
+                x => panic!("Mini surge flag should be 0 or 1, got {x}"),
+            };
+            abilities.push(Self::Surge(Surge {
+                stype,
+                chance: variable.surge_chance,
+                spawn_quad: variable.surge_spawn_quad,
+                range_quad: variable.surge_range_quad,
+                level: variable.surge_level,
+            }));
+        }
+
+        if chance(variable.curse_chance) {
+            abilities.push(Self::Curse {
+                chance: variable.curse_chance,
+                duration: variable.curse_duration,
+            });
+        }
+
+        if bool(variable.has_counter_surge).unwrap() {
+            abilities.push(Self::CounterSurge);
+        }
+
+        if chance(variable.explosion_chance) {
+            abilities.push(Self::Explosion {
+                chance: variable.explosion_chance,
+                spawn_quad: variable.explosion_spawn_quad,
+            });
+        }
+
+        abilities
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
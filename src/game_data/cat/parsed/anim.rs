@@ -2,6 +2,7 @@
 
 use super::unitbuy::AncientEggInfo;
 use crate::game_data::version::Version;
+use serde::Serialize;
 use std::{
     cmp::max,
     fs::File,
@@ -19,7 +20,7 @@ pub enum AnimDataError {
     ReadFileError(usize, std::io::Error),
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Data about a single animation.
 pub struct Anim {
     length: u16, // right now all that's needed is the length of the animation
@@ -37,7 +38,7 @@ impl Anim {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Data about a unit form's animations.
 pub struct CatFormAnimData {
     /// Attack animation.
@@ -78,7 +79,9 @@ pub fn get_anims(
     Ok(anims)
 }
 
-fn get_anim_data(path: &str, version: &Version) -> Result<CatFormAnimData, AnimDataError> {
+/// Get attack animation data from the `.maanim` file at
+/// `ImageDataLocal/{path}`.
+pub fn get_anim_data(path: &str, version: &Version) -> Result<CatFormAnimData, AnimDataError> {
     use AnimDataError as E;
     let qualified = version.get_file_path("ImageDataLocal").join(path);
 
@@ -9,7 +9,7 @@ use crate::game_data::{
     cat::{
         parsed::{anim::Anim, talents::Talents},
         raw::{
-            stats::read_data_file,
+            stats::{CombinedCatData, read_data_file},
             talents::TalentsContainer,
             unitbuy::UnitBuyContainer,
             unitexp::XPCostScale,
@@ -21,9 +21,10 @@ use crate::game_data::{
         lang::{MultiLangVersionContainer, VersionLanguage},
     },
 };
+use serde::Serialize;
 use std::iter::zip;
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Data about individual forms of the cat.
 pub struct CatForms {
     /// Amount of forms the cat has.
@@ -44,7 +45,7 @@ impl CatForms {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Parsed cat object.
 pub struct Cat {
     /// CRO id.
@@ -181,13 +182,29 @@ impl Cat {
         })
     }
 
-    /// Get stats for each form.
-    pub fn get_stats(wiki_id: u32, version: &Version) -> impl Iterator<Item = CatFormStats> {
-        // get_stats(wiki_id + 1, version)
+    /// Get raw data for each form.
+    fn read_forms(wiki_id: u32, version: &Version) -> impl Iterator<Item = CombinedCatData> {
         let abs_id = wiki_id + 1;
         let file_name = format!("unit{abs_id:03}.csv");
-        let combined_iter = read_data_file(&file_name, version);
-        combined_iter.map(|combined| CatFormStats::from_combined(&combined))
+        read_data_file(&file_name, version)
+    }
+
+    /// Get stats for each form.
+    pub fn get_stats(wiki_id: u32, version: &Version) -> impl Iterator<Item = CatFormStats> {
+        Self::read_forms(wiki_id, version).map(|combined| CatFormStats::from_combined(&combined))
+    }
+
+    /// Get stats for each form that can use talents (i.e. true form onwards)
+    /// with every talent unlocked.
+    pub fn get_talented_stats(&self, talents: &Talents, version: &Version) -> Vec<CatFormStats> {
+        Self::read_forms(self.id, version)
+            .take(self.forms.amt_forms)
+            .skip(2)
+            .map(|mut combined| {
+                talents.apply(&mut combined);
+                CatFormStats::from_combined(&combined)
+            })
+            .collect()
     }
 }
 
@@ -2,10 +2,11 @@
 
 use super::super::super::{ability::Ability, raw::stats::CombinedCatData};
 use crate::game_data::cat::raw::unitlevel::UnitLevelRaw;
+use serde::Serialize;
 use std::{fmt::Display, num::NonZero};
 
 #[repr(usize)]
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize)]
 /// Enemy types that can be targeted.
 pub enum EnemyType {
     /// Red.
@@ -100,7 +101,7 @@ fn bool(value: u8) -> Result<bool, String> {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
 /// Range of an attack.
 pub enum AttackRange {
     /// Range is standing range.
@@ -124,7 +125,8 @@ pub enum AttackRange {
     Unchanged,
 }
 impl AttackRange {
-    const fn new(base: i16, distance: i16) -> Self {
+    /// Get attack range from raw LD values.
+    pub const fn new(base: i16, distance: i16) -> Self {
         if base == 0 {
             AttackRange::Normal
         } else if distance > 0 {
@@ -147,7 +149,7 @@ impl AttackRange {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
 /// Single hit of the unit's attack.
 pub struct AttackHit {
     /// Is the ability active on this hit.
@@ -160,7 +162,7 @@ pub struct AttackHit {
     pub foreswing: u16,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// The unit's attacks.
 pub enum AttackHits {
     /// One attack.
@@ -384,7 +386,7 @@ mod hit_tests {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Area of the unit's hits.
 pub enum AreaOfEffect {
     /// First enemy in range.
@@ -393,7 +395,7 @@ pub enum AreaOfEffect {
     AreaAttack,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Unit's attack.
 pub struct Attack {
     /// All hits of the unit's attack.
@@ -436,7 +438,7 @@ impl Attack {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Stats at level 1 with no treasures.
 pub struct CatFormStats {
     /// Unit HP.
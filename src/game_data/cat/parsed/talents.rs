@@ -1,6 +1,9 @@
 //! Parsed talents object.
 
-use crate::game_data::cat::raw::talents::{TalentGroup, TalentLine};
+use crate::game_data::cat::raw::{
+    stats::CombinedCatData,
+    talents::{TalentGroup, TalentLine},
+};
 use std::num::NonZeroUsize;
 use strum::FromRepr;
 
@@ -43,6 +46,26 @@ impl TalentTargets {
             })
             .collect()
     }
+
+    /// Make the cat target this type in its raw data. "Maybe" and unknown
+    /// targets are ignored.
+    fn apply(&self, (fixed, var): &mut CombinedCatData) {
+        match self {
+            Self::Metal => fixed.targ_metal = 1,
+            Self::Alien => fixed.targ_alien = 1,
+            Self::Zombie => fixed.targ_zombie = 1,
+            Self::Relic => var.targ_relic = 1,
+            Self::MaybeRed
+            | Self::MaybeFloating
+            | Self::MaybeBlack
+            | Self::MaybeAngel
+            | Self::MaybeTraitless
+            | Self::Unknown1
+            | Self::Unknown2
+            | Self::MaybeAku
+            | Self::AsYetUnknown => (),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, FromRepr)]
@@ -110,6 +133,118 @@ impl SingleTalent {
 
         Some(t)
     }
+
+    /// Max value of the talent's `i`th parameter, or 0 if it doesn't have one.
+    fn max_param(&self, i: usize) -> u16 {
+        self.params.get(i).map_or(0, |&(_, max)| max)
+    }
+
+    /// Max value of the talent's `i`th parameter as a percentage (at least 1%
+    /// so that the ability is always unlocked).
+    fn max_percent(&self, i: usize) -> u8 {
+        u8::try_from(self.max_param(i)).unwrap_or(100).max(1)
+    }
+
+    /// Unlock the ability or target the talent gives in the cat's raw data,
+    /// using the talent's max level.
+    ///
+    /// Abilities the cat already has are left alone, so talents that only
+    /// upgrade an ability or the cat's stats don't change anything.
+    pub fn apply(&self, combined: &mut CombinedCatData) {
+        let (fixed, var) = combined;
+        match self.ability_id.get() {
+            1 if fixed.weaken_chance == 0 => {
+                fixed.weaken_chance = self.max_percent(0);
+                fixed.weaken_duration = self.max_param(1);
+                fixed.weaken_multiplier = self.max_percent(2);
+            }
+            2 if fixed.freeze_chance == 0 => {
+                fixed.freeze_chance = self.max_percent(0);
+                fixed.freeze_duration = self.max_param(1);
+            }
+            3 if fixed.slow_chance == 0 => {
+                fixed.slow_chance = self.max_percent(0);
+                fixed.slow_duration = self.max_param(1);
+            }
+            4 => fixed.has_targets_only = 1,
+            5 => fixed.has_strong = 1,
+            6 => fixed.has_resist = 1,
+            7 => fixed.has_massive_damage = 1,
+            8 if fixed.kb_chance == 0 => fixed.kb_chance = self.max_percent(0),
+            10 if fixed.strengthen_hp == 0 => {
+                fixed.strengthen_hp = self.max_percent(0);
+                fixed.strengthen_multiplier = self.max_param(1);
+            }
+            11 if fixed.survives_chance == 0 => fixed.survives_chance = self.max_percent(0),
+            12 => fixed.has_base_destroyer = 1,
+            13 if fixed.crit_chance == 0 => fixed.crit_chance = self.max_percent(0),
+            14 => var.has_zombie_killer = Some(1),
+            15 if var.barrier_break_chance == 0 => {
+                var.barrier_break_chance = self.max_percent(0);
+            }
+            16 => fixed.has_double_bounty = 1,
+            17 | 62 if fixed.wave_chance == 0 => {
+                fixed.wave_chance = self.max_percent(0);
+                fixed.wave_level = u8::try_from(self.max_param(1)).unwrap_or(u8::MAX);
+                var.is_mini_wave = u8::from(self.ability_id.get() == 62);
+            }
+            23 => fixed.immune_wave = 1,
+            29 => var.immune_curse = 1,
+            33 => fixed.targ_red = 1,
+            34 => fixed.targ_float = 1,
+            35 => fixed.targ_black = 1,
+            36 => fixed.targ_metal = 1,
+            37 => fixed.targ_angel = 1,
+            38 => fixed.targ_alien = 1,
+            39 => fixed.targ_zombie = 1,
+            40 => var.targ_relic = 1,
+            41 => fixed.targ_traitless = 1,
+            44 => fixed.immune_weaken = 1,
+            45 => fixed.immune_freeze = 1,
+            46 => fixed.immune_slow = 1,
+            47 => fixed.immune_kb = 1,
+            48 => fixed.immune_wave = 1,
+            49 => var.immune_warp = 1,
+            50 if var.savage_blow_chance == 0 => {
+                var.savage_blow_chance = self.max_percent(0);
+                var.savage_blow_percent = self.max_param(1);
+            }
+            51 if var.dodge_chance == 0 => {
+                var.dodge_chance = self.max_percent(0);
+                var.dodge_duration = self.max_param(1);
+            }
+            53 => var.immune_toxic = 1,
+            55 => var.immune_surge = 1,
+            56 | 65 if var.surge_chance == 0 => {
+                var.surge_chance = self.max_percent(0);
+                var.surge_level = u8::try_from(self.max_param(1)).unwrap_or(u8::MAX);
+                var.surge_spawn_quad = self.max_param(2);
+                var.surge_range_quad = self.max_param(3);
+                var.is_mini_surge = u8::from(self.ability_id.get() == 65);
+            }
+            57 => var.targ_aku = 1,
+            58 if var.shield_pierce_chance == 0 => {
+                var.shield_pierce_chance = self.max_percent(0);
+            }
+            59 => var.has_soulstrike = 1,
+            60 if var.curse_chance == 0 => {
+                var.curse_chance = self.max_percent(0);
+                var.curse_duration = self.max_param(1);
+            }
+            63 => var.has_colossus_slayer = 1,
+            64 if var.has_behemoth_slayer == 0 => {
+                var.has_behemoth_slayer = 1;
+                var.bslayer_dodge_chance = self.max_percent(0);
+                var.bslayer_dodge_duration = self.max_param(1);
+            }
+            66 => var.has_sage_slayer = 1,
+            67 if var.explosion_chance == 0 => {
+                var.explosion_chance = self.max_percent(0);
+                var.explosion_spawn_quad = self.max_param(1);
+            }
+            _ => (),
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -151,6 +286,18 @@ impl Talents {
             ultra,
         }
     }
+
+    /// Unlock every normal and ultra talent in the cat's raw data, as well as
+    /// the targets the talents implicitly enable (see
+    /// [`SingleTalent::apply`]).
+    pub fn apply(&self, combined: &mut CombinedCatData) {
+        for target in &self.implicit_targets {
+            target.apply(combined);
+        }
+        for talent in self.normal.iter().chain(&self.ultra) {
+            talent.apply(combined);
+        }
+    }
 }
 
 #[cfg(test)]
@@ -194,6 +341,36 @@ mod tests {
         }
     }
 
+    #[test]
+    fn apply_talents() {
+        use crate::game_data::cat::{ability::Ability, parsed::stats::form::EnemyType};
+
+        let talent = |ability_id, params| SingleTalent {
+            ability_id: NonZeroUsize::new(ability_id).unwrap(),
+            max_level: 10,
+            params,
+            skill_description_id: 0,
+            skill_costs_id: 0,
+            name_id_or_something: -1,
+            ttype: TalentType::Normal,
+        };
+
+        let mut combined = CombinedCatData::default();
+        talent(40, vec![]).apply(&mut combined);
+        talent(1, vec![(20, 20), (60, 150), (50, 50)]).apply(&mut combined);
+        talent(32, vec![(4, 40)]).apply(&mut combined);
+
+        assert_eq!(EnemyType::get_all_targets(&combined), [EnemyType::Relic]);
+        assert_eq!(
+            Ability::get_all_abilities(&combined),
+            [Ability::Weaken {
+                chance: 20,
+                duration: 150,
+                multiplier: 50
+            }]
+        );
+    }
+
     #[test]
     fn type_ids() {
         let version = TEST_CONFIG.version.current_version();
@@ -1,13 +1,14 @@
 //! Data from `unitbuy.csv`.
 
 use crate::game_data::cat::raw::unitbuy::UnitBuyRaw;
+use serde::Serialize;
 use std::{fmt::Display, num::NonZero};
 use strum::{EnumIter, FromRepr};
 pub mod evolution_items;
 mod tests;
 
 #[repr(u8)]
-#[derive(Debug, FromRepr, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Debug, FromRepr, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
 /// Currency used to unlock a unit.
 pub enum UnlockCurrency {
     /// XP unlock.
@@ -18,7 +19,7 @@ pub enum UnlockCurrency {
     #[default]
     None = 2,
 }
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Default, Serialize)]
 /// How the cat is unlocked.
 pub struct CatUnlock {
     /// EoC stage the unit is available (cat is available before stage 0, tank
@@ -57,7 +58,7 @@ impl CatUnlock {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Item used in Catfruit evolutions.
 pub struct EvolutionItem {
     /// Item ID.
@@ -66,7 +67,7 @@ pub struct EvolutionItem {
     pub item_amt: u8,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Descriptor for a unit's Catfruit evolution.
 pub struct CatfruitEvolution {
     /// Catfruit/B.Stone cost.
@@ -77,7 +78,7 @@ pub struct CatfruitEvolution {
     pub level_required: u8,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// How the unit might evolve into a specific form.
 pub enum EvolutionType {
     /// Evolves by getting to level.
@@ -92,7 +93,7 @@ pub enum EvolutionType {
     Other,
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// How the unit might evolve.
 pub struct EvolutionInfo {
     /// ID of evolution.
@@ -113,7 +114,7 @@ impl EvolutionInfo {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Base cost of upgrading the unit each level.
 ///
 /// This is not the full picture, this will also require usage of
@@ -142,7 +143,7 @@ impl UpgradeCost {
 }
 
 /// Information from the page "Level-up".
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub struct MaxLevels {
     /// Normal level cap.
     pub ch1: u8,
@@ -168,7 +169,7 @@ impl MaxLevels {
 }
 
 #[repr(u8)]
-#[derive(Debug, FromRepr, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, EnumIter)]
+#[derive(Debug, FromRepr, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, EnumIter, Serialize)]
 /// Rarity of unit.
 pub enum Rarity {
     /// Normal.
@@ -216,7 +217,7 @@ impl Display for Rarity {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Info about Ancient Eggs.
 pub enum AncientEggInfo {
     /// Unit is not an egg.
@@ -234,7 +235,7 @@ pub enum AncientEggInfo {
     },
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize)]
 /// Order of unit in the Cat Guide.
 pub enum CatGuideOrder {
     /// Unit with id.
@@ -260,7 +261,7 @@ impl From<i32> for CatGuideOrder {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Miscellaneous unitbuy data.
 pub struct Misc {
     /// Unit's rarity.
@@ -302,7 +303,7 @@ impl Misc {
     }
 }
 
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// All data contained in `unitbuy.csv`.
 pub struct UnitBuy {
     /// How to unlock the unit.
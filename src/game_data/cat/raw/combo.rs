@@ -122,7 +122,7 @@ fn get_combodata<T: for<'a> Deserialize<'a> + Into<ComboData>>(
 }
 
 #[repr(i16)]
-#[derive(Debug, PartialEq, Eq, Clone, Copy, FromRepr)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, FromRepr, serde::Serialize)]
 /// How the combo is unlocked.
 pub enum ComboUnlockType {
     /// E.g. removed combos.
@@ -143,7 +143,7 @@ pub enum ComboUnlockType {
     Rank2700 = 10003,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+#[derive(Debug, PartialEq, Eq, Clone, Copy, serde::Serialize)]
 /// Unit in a combo.
 pub struct ComboUnit {
     /// 0 = cat.
@@ -157,7 +157,7 @@ impl ComboUnit {
     }
 }
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, PartialEq, Eq, serde::Serialize)]
 /// Data about an individual combo.
 pub struct ComboData {
     /// Visibility conditions of the combo.
@@ -55,4 +55,9 @@ impl EquipmentSlotContainer {
     pub fn get_slot_item(&self, cat_id: usize) -> Option<&EquipmentSlotItem> {
         self.slots.iter().find(|slot| slot.unit_id == cat_id)
     }
+
+    /// Get the amount of talent orb slots the unit with id has.
+    pub fn amt_slots(&self, cat_id: usize) -> u8 {
+        self.get_slot_item(cat_id).map_or(0, |slot| slot.amt_slots)
+    }
 }
@@ -5,6 +5,7 @@ pub mod combo_local;
 pub mod desc;
 pub mod equipmentslot;
 pub mod evolution_desc;
+pub mod orb;
 pub mod stats;
 pub mod talents;
 pub mod talents_cost;
@@ -0,0 +1,250 @@
+//! Talent orb definitions.
+//!
+//! `equipmentlist.json` contains a list of every orb, where the list index is
+//! the orb's id. Each orb has its effect type (`content`), a bitmask of the
+//! traits it affects (`attribute`, with the bits in the same order as
+//! [`EnemyType`]) and the effect's values for each grade (`value`).
+
+use crate::game_data::{
+    cat::parsed::stats::form::EnemyType,
+    version::{
+        Version,
+        version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+
+/// Name of each orb grade, from worst to best.
+pub const GRADES: [&str; 5] = ["D", "C", "B", "A", "S"];
+
+/// Traits in the order of the bits of `attribute`.
+const TRAITS: [EnemyType; 10] = [
+    EnemyType::Red,
+    EnemyType::Floating,
+    EnemyType::Black,
+    EnemyType::Metal,
+    EnemyType::Traitless,
+    EnemyType::Angel,
+    EnemyType::Alien,
+    EnemyType::Zombie,
+    EnemyType::Relic,
+    EnemyType::Aku,
+];
+
+#[derive(Debug, Deserialize)]
+struct RawOrb {
+    content: u8,
+    attribute: u16,
+    value: Vec<Vec<u32>>,
+}
+
+#[derive(Debug, Deserialize)]
+struct OrbList {
+    #[serde(rename = "ID")]
+    id: Vec<RawOrb>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// Effect of a talent orb.
+pub enum OrbEffect {
+    /// Increases attack power.
+    Attack,
+    /// Reduces damage taken.
+    Defense,
+    /// Increases the strength of the Strong ability.
+    Strong,
+    /// Increases the strength of the Massive Damage ability.
+    MassiveDamage,
+    /// Increases the strength of the Resistant ability.
+    Resistant,
+    /// Effect that hasn't been added yet.
+    Unknown(u8),
+}
+impl OrbEffect {
+    const fn from_content(content: u8) -> Self {
+        match content {
+            0 => Self::Attack,
+            1 => Self::Defense,
+            2 => Self::Strong,
+            3 => Self::MassiveDamage,
+            4 => Self::Resistant,
+            x => Self::Unknown(x),
+        }
+    }
+
+    /// Name of the effect.
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Attack => "Attack",
+            Self::Defense => "Defense",
+            Self::Strong => "Strong",
+            Self::MassiveDamage => "Massive Damage",
+            Self::Resistant => "Resistant",
+            Self::Unknown(_) => "Unknown",
+        }
+    }
+
+    /// Describe the effect of a single grade.
+    pub fn describe(&self, values: &[u32]) -> String {
+        let value = |i: usize| values.get(i).copied().unwrap_or_default();
+        match self {
+            Self::Attack => format!("+{}% attack power", value(0)),
+            Self::Defense => format!("-{}% damage taken", value(0)),
+            Self::Strong => format!(
+                "+{}% damage dealt and -{}% damage taken",
+                value(0),
+                value(1)
+            ),
+            Self::MassiveDamage => format!("+{}% damage dealt", value(0)),
+            Self::Resistant => format!("-{}% damage taken", value(0)),
+            Self::Unknown(_) => values
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(", "),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Single talent orb.
+pub struct Orb {
+    /// Orb's id.
+    pub id: u32,
+    /// Orb's effect.
+    pub effect: OrbEffect,
+    /// Traits the orb affects.
+    pub traits: Vec<EnemyType>,
+    /// Effect values for each grade, from worst to best.
+    pub grades: Vec<Vec<u32>>,
+}
+impl Orb {
+    fn from_raw(id: u32, raw: RawOrb) -> Self {
+        let traits = TRAITS
+            .into_iter()
+            .enumerate()
+            .filter(|(bit, _)| raw.attribute & (1 << bit) != 0)
+            .map(|(_, enemy_type)| enemy_type)
+            .collect();
+
+        Self {
+            id,
+            effect: OrbEffect::from_content(raw.content),
+            traits,
+            grades: raw.value,
+        }
+    }
+
+    /// Name of the orb (e.g. "Red Attack Orb", or "Attack Orb" if it has no
+    /// traits).
+    pub fn name(&self) -> String {
+        let effect = self.effect.name();
+        if self.traits.is_empty() {
+            return format!("{effect} Orb");
+        }
+
+        let traits = self
+            .traits
+            .iter()
+            .map(EnemyType::to_string)
+            .collect::<Vec<_>>()
+            .join("/");
+        format!("{traits} {effect} Orb")
+    }
+}
+
+#[derive(Debug, Default)]
+/// Container for talent orbs.
+pub struct Orbs {
+    orbs: Vec<Orb>,
+}
+impl Orbs {
+    /// Get the orb with id `id`.
+    pub fn get_orb(&self, id: u32) -> Option<&Orb> {
+        self.orbs.get(id as usize)
+    }
+
+    /// Iterate over all orbs.
+    pub fn iter(&self) -> impl Iterator<Item = &Orb> {
+        self.orbs.iter()
+    }
+}
+impl From<OrbList> for Orbs {
+    fn from(value: OrbList) -> Self {
+        let orbs = (0..)
+            .zip(value.id)
+            .map(|(id, raw)| Orb::from_raw(id, raw))
+            .collect();
+        Self { orbs }
+    }
+}
+impl CacheableVersionData for Orbs {
+    fn create(version: &Version) -> CvdResult<Self> {
+        let file = File::open(version.location().join("DataLocal/equipmentlist.json"))
+            .map_err(CvdCreateError::default_from_err)?;
+        let data: OrbList =
+            serde_json::from_reader(file).map_err(CvdCreateError::throw_from_err)?;
+        Ok(data.into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn parse_orbs() {
+        let data: OrbList = serde_json::from_str(
+            r#"{"ID": [
+                {"content": 0, "attribute": 1, "value": [[1], [2], [3], [4], [5]]},
+                {"content": 2, "attribute": 34, "value": [[2, 1]]}
+            ]}"#,
+        )
+        .unwrap();
+        let orbs = Orbs::from(data);
+
+        let red_attack = orbs.get_orb(0).unwrap();
+        assert_eq!(red_attack.name(), "Red Attack Orb");
+        assert_eq!(
+            red_attack.effect.describe(&red_attack.grades[4]),
+            "+5% attack power"
+        );
+
+        let strong = orbs.get_orb(1).unwrap();
+        assert_eq!(strong.traits, [EnemyType::Floating, EnemyType::Angel]);
+        assert_eq!(strong.name(), "Floating/Angel Strong Orb");
+        assert_eq!(
+            strong.effect.describe(&strong.grades[0]),
+            "+2% damage dealt and -1% damage taken"
+        );
+    }
+
+    #[test]
+    fn traitless_orb() {
+        let data: OrbList =
+            serde_json::from_str(r#"{"ID": [{"content": 0, "attribute": 0, "value": [[1]]}]}"#)
+                .unwrap();
+        let orbs = Orbs::from(data);
+
+        let orb = orbs.get_orb(0).unwrap();
+        assert!(orb.traits.is_empty());
+        assert_eq!(orb.name(), "Attack Orb");
+    }
+
+    #[test]
+    fn real_orbs() {
+        let orbs = TEST_CONFIG
+            .version
+            .current_version()
+            .get_cached_file::<Orbs>();
+
+        // first entry in `equipmentlist.json` is `attribute: 1`, the red attack
+        // orb
+        let first = orbs.get_orb(0).unwrap();
+        assert_eq!(first.effect, OrbEffect::Attack);
+        assert_eq!(first.traits, [EnemyType::Red]);
+        assert_eq!(first.grades.len(), GRADES.len());
+    }
+}
@@ -23,7 +23,7 @@ type Bool = u8;
 /// CSV data about a cat.
 pub type CombinedCatData = (CatCSV, CatCSV2);
 
-#[derive(Debug, serde::Deserialize, Default)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Default)]
 #[allow(missing_docs)]
 /// Fixed CSV data.
 pub struct CatCSV {
@@ -104,7 +104,7 @@ pub struct CatCSV {
     pub immune_weaken: Bool,
 }
 
-#[derive(Debug, Default, serde::Deserialize)]
+#[derive(Debug, Default, serde::Deserialize, serde::Serialize)]
 #[serde(default)]
 #[allow(missing_docs)]
 /// Data that may not exist. All fields default to `0` if not explicitly given.
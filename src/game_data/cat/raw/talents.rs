@@ -15,7 +15,7 @@ use std::{
 use string_error::into_err;
 
 /// Talents block.
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct TalentsFixed {
     /// ID of cat unit.
     pub id: u16,
@@ -24,7 +24,7 @@ pub struct TalentsFixed {
 }
 
 /// Repeated group of talents.
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct TalentGroup {
     /// ID of ability affected by talent.
     pub ability_id_x: u8,
@@ -58,7 +58,7 @@ pub struct TalentGroup {
 const AMT_GROUPS: usize = 8;
 
 /// Container for a single line of talents.
-#[derive(Debug)]
+#[derive(Debug, serde::Serialize)]
 pub struct TalentLine {
     /// Fixed data for each line.
     pub fixed: TalentsFixed,
@@ -8,7 +8,7 @@ use csv::ByteRecord;
 use std::fmt::Debug;
 use string_error::into_err;
 
-#[derive(Debug, serde::Deserialize, Default)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Default)]
 #[allow(missing_docs)]
 pub struct UnitBuyRaw {
     /// Amount of stages to clear in chapter before unit is available (e.g. 0
@@ -144,6 +144,11 @@ impl UnitBuyContainer {
     pub fn get_unit(&self, id: u32) -> Option<&UnitBuyRaw> {
         self.units.get(id as usize)
     }
+
+    /// Iterate over every unit's [`UnitBuyRaw`] line.
+    pub fn iter(&self) -> impl Iterator<Item = &UnitBuyRaw> {
+        self.units.iter()
+    }
 }
 impl CacheableVersionData for UnitBuyContainer {
     fn create(version: &Version) -> CvdResult<Self> {
@@ -6,7 +6,9 @@ use crate::game_data::version::{
 };
 use std::path::Path;
 
-#[derive(Debug, serde::Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Default)]
+#[derive(
+    Debug, serde::Deserialize, PartialEq, Eq, PartialOrd, Ord, Clone, Default, serde::Serialize,
+)]
 /// Level-up scale multiplier per 10 levels.
 ///
 /// All values are multiplied by 100 to avoid using floats.
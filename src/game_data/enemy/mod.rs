@@ -1,2 +1,5 @@
 //! Information about an enemy.
+
+pub mod parsed;
+pub mod raw;
 pub mod raw_encounters;
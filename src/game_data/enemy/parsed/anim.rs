@@ -0,0 +1,36 @@
+//! Deals with enemy animation data.
+
+use crate::game_data::{
+    cat::parsed::anim::{Anim, AnimDataError, get_anim_data},
+    version::Version,
+};
+use serde::Serialize;
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+/// Data about an enemy's animations.
+pub struct EnemyAnimData {
+    /// Attack animation.
+    pub attack: Anim,
+}
+
+/// Get enemy animations.
+pub fn get_enemy_anims(wiki_id: u32, version: &Version) -> Result<EnemyAnimData, AnimDataError> {
+    let file_name = format!("{wiki_id:03}_e02.maanim");
+    let data = get_anim_data(&file_name, version)?;
+    Ok(EnemyAnimData {
+        attack: data.attack,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn doge() {
+        let version = TEST_CONFIG.version.current_version();
+        let doge = get_enemy_anims(0, version).unwrap();
+        assert!(doge.attack.length() > 0);
+    }
+}
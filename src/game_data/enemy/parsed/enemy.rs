@@ -0,0 +1,75 @@
+//! Deals with enemy data.
+
+use super::{
+    anim::{EnemyAnimData, get_enemy_anims},
+    stats::EnemyStats,
+};
+use crate::game_data::{
+    cat::parsed::anim::AnimDataError,
+    enemy::raw::stats::EnemyStatsContainer,
+    version::lang::{MultiLangVersionContainer, VersionLanguage},
+};
+use serde::Serialize;
+
+#[derive(Debug, Serialize)]
+/// Parsed enemy object.
+pub struct Enemy {
+    /// Wiki id (Doge = 0).
+    pub id: u32,
+    /// Enemy stats.
+    pub stats: EnemyStats,
+    /// Enemy animation data.
+    pub anim: EnemyAnimData,
+}
+
+#[derive(Debug)]
+/// Error when getting enemy data.
+pub enum EnemyDataError {
+    /// No data in `t_unit.csv`. Almost certainly means that the enemy does not
+    /// exist in the current version.
+    StatsNotFound,
+    /// Error with enemy animations.
+    AnimationError(AnimDataError),
+}
+
+impl Enemy {
+    /// Get enemy from wiki id.
+    pub fn from_wiki_id<T: MultiLangVersionContainer>(
+        wiki_id: u32,
+        version_cont: &T,
+    ) -> Result<Self, EnemyDataError> {
+        type E = EnemyDataError;
+        let id = wiki_id;
+
+        let container = version_cont
+            .lang_default()
+            .get_cached_file::<EnemyStatsContainer>();
+        let stats = EnemyStats::from_combined(container.get_enemy(id).ok_or(E::StatsNotFound)?);
+
+        let get = |lang| get_enemy_anims(id, version_cont.get_lang(lang));
+        let anim = get(VersionLanguage::EN)
+            .or_else(|_| get(VersionLanguage::JP))
+            .or_else(|_| get(VersionLanguage::Fallback))
+            .map_err(E::AnimationError)?;
+
+        Ok(Self { id, stats, anim })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TEST_CONFIG,
+        game_data::cat::parsed::stats::form::{AreaOfEffect, EnemyType},
+    };
+
+    #[test]
+    fn doge() {
+        let doge = Enemy::from_wiki_id(0, &TEST_CONFIG.version).unwrap();
+        assert_eq!(doge.stats.hp, 90);
+        assert_eq!(doge.stats.attack.aoe, AreaOfEffect::SingleAttack);
+        assert_eq!(doge.stats.traits, [EnemyType::Traitless]);
+        assert!(doge.stats.abilities.is_empty());
+    }
+}
@@ -0,0 +1,5 @@
+//! High-level container for enemy data.
+
+pub mod anim;
+pub mod enemy;
+pub mod stats;
@@ -0,0 +1,421 @@
+//! Deals with enemy stats.
+
+use crate::game_data::{
+    cat::{
+        ability::{Ability, Surge, SurgeType},
+        parsed::stats::form::{AreaOfEffect, AttackHit, AttackHits, AttackRange, EnemyType},
+    },
+    enemy::raw::stats::CombinedEnemyData,
+};
+use serde::Serialize;
+use std::fmt::Display;
+
+fn bool(value: u8) -> Result<bool, String> {
+    match value {
+        0 => Ok(false),
+        1 => Ok(true),
+        x => Err(format!("{x} is not a valid boolean number!")),
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Copy, Clone, Serialize)]
+/// Enemy traits that aren't regular targetable colours.
+pub enum EnemySubtype {
+    /// Witch.
+    Witch,
+    /// EVA Angel.
+    EvaAngel,
+    /// Colossus.
+    Colossus,
+    /// Behemoth.
+    Behemoth,
+    /// Sage.
+    Sage,
+    /// Supervillain.
+    Supervillain,
+}
+impl Display for EnemySubtype {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let t = match self {
+            Self::Witch => "Witch",
+            Self::EvaAngel => "EVA Angel",
+            Self::Colossus => "Colossus",
+            Self::Behemoth => "Behemoth",
+            Self::Sage => "Sage",
+            Self::Supervillain => "Supervillain",
+        };
+        f.write_str(t)
+    }
+}
+
+/// Get all of the enemy's traits.
+pub fn get_enemy_traits(combined: &CombinedEnemyData) -> Vec<EnemyType> {
+    let (fixed, variable) = combined;
+    let mut traits = vec![];
+
+    if bool(fixed.is_red).unwrap() {
+        traits.push(EnemyType::Red);
+    }
+    if bool(fixed.is_floating).unwrap() {
+        traits.push(EnemyType::Floating);
+    }
+    if bool(fixed.is_black).unwrap() {
+        traits.push(EnemyType::Black);
+    }
+    if bool(fixed.is_metal).unwrap() {
+        traits.push(EnemyType::Metal);
+    }
+    if bool(fixed.is_traitless).unwrap() {
+        traits.push(EnemyType::Traitless);
+    }
+    if bool(fixed.is_angel).unwrap() {
+        traits.push(EnemyType::Angel);
+    }
+    if bool(fixed.is_alien).unwrap() {
+        traits.push(EnemyType::Alien);
+    }
+    if bool(fixed.is_zombie).unwrap() {
+        traits.push(EnemyType::Zombie);
+    }
+    if bool(variable.is_relic).unwrap() {
+        traits.push(EnemyType::Relic);
+    }
+    if bool(variable.is_aku).unwrap() {
+        traits.push(EnemyType::Aku);
+    }
+
+    traits
+}
+
+/// Get all of the enemy's subtypes.
+pub fn get_enemy_subtypes(combined: &CombinedEnemyData) -> Vec<EnemySubtype> {
+    let (fixed, variable) = combined;
+    let mut subtypes = vec![];
+
+    if bool(fixed.is_witch).unwrap() {
+        subtypes.push(EnemySubtype::Witch);
+    }
+    if bool(variable.is_eva_angel).unwrap() {
+        subtypes.push(EnemySubtype::EvaAngel);
+    }
+    if bool(variable.is_colossus).unwrap() {
+        subtypes.push(EnemySubtype::Colossus);
+    }
+    if bool(variable.is_behemoth).unwrap() {
+        subtypes.push(EnemySubtype::Behemoth);
+    }
+    if bool(variable.is_sage).unwrap() {
+        subtypes.push(EnemySubtype::Sage);
+    }
+    if bool(variable.is_supervillain).unwrap() {
+        subtypes.push(EnemySubtype::Supervillain);
+    }
+
+    subtypes
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Serialize)]
+/// Abilities that only enemies can have.
+pub enum EnemyAbility {
+    /// Burrow underground.
+    Burrow {
+        /// Amount of times enemy burrows. [`None`] means unlimited.
+        amount: Option<u8>,
+        /// Distance travelled underground * 4.
+        distance_quad: u16,
+    },
+    /// Revive after death.
+    Revive {
+        /// Amount of times enemy revives. [`None`] means unlimited.
+        amount: Option<u8>,
+        /// Time spent dead (f).
+        time: u16,
+        /// % of HP revived with.
+        hp: u16,
+    },
+    /// Warp cats.
+    Warp {
+        /// Chance to warp.
+        chance: u8,
+        /// Duration of warp (f).
+        duration: u16,
+        /// Min distance * 4.
+        min_quad: i32,
+        /// Max distance * 4.
+        max_quad: i32,
+    },
+    /// Barrier.
+    Barrier {
+        /// HP of the barrier.
+        hp: u32,
+    },
+    /// Toxic.
+    Toxic {
+        /// Chance to poison.
+        chance: u8,
+        /// % of cat's max HP dealt as damage.
+        damage: u8,
+    },
+    /// Aku shield.
+    Shield {
+        /// HP of the shield.
+        hp: u32,
+        /// % of shield HP regenerated when knocked back.
+        regen: u16,
+    },
+    /// Surge on death.
+    DeathSurge(Surge),
+    /// Starred alien.
+    Starred {
+        /// Amount of stars.
+        stars: u8,
+    },
+}
+
+impl EnemyAbility {
+    /// Get the enemy's enemy-only abilities.
+    pub fn get_all_abilities(combined: &CombinedEnemyData) -> Vec<EnemyAbility> {
+        let (fixed, variable) = combined;
+        let mut abilities = vec![];
+
+        if fixed.burrow_amount != 0 {
+            abilities.push(Self::Burrow {
+                amount: u8::try_from(fixed.burrow_amount).ok(),
+                distance_quad: fixed.burrow_distance_quad,
+            });
+        }
+
+        if fixed.revive_amount != 0 {
+            abilities.push(Self::Revive {
+                amount: u8::try_from(fixed.revive_amount).ok(),
+                time: fixed.revive_time,
+                hp: fixed.revive_hp,
+            });
+        }
+
+        if variable.warp_chance > 0 {
+            abilities.push(Self::Warp {
+                chance: variable.warp_chance,
+                duration: variable.warp_duration,
+                min_quad: variable.warp_min_quad,
+                max_quad: variable.warp_max_quad,
+            });
+        }
+
+        if variable.barrier_hp > 0 {
+            abilities.push(Self::Barrier {
+                hp: variable.barrier_hp,
+            });
+        }
+
+        if variable.toxic_chance > 0 {
+            abilities.push(Self::Toxic {
+                chance: variable.toxic_chance,
+                damage: variable.toxic_percent,
+            });
+        }
+
+        if variable.shield_hp > 0 {
+            abilities.push(Self::Shield {
+                hp: variable.shield_hp,
+                regen: variable.shield_regen_percent,
+            });
+        }
+
+        if variable.death_surge_chance > 0 {
+            abilities.push(Self::DeathSurge(Surge {
+                stype: SurgeType::Surge,
+                chance: variable.death_surge_chance,
+                spawn_quad: variable.death_surge_spawn_quad,
+                range_quad: variable.death_surge_range_quad,
+                level: variable.death_surge_level,
+            }));
+        }
+
+        if variable.starred > 0 {
+            abilities.push(Self::Starred {
+                stars: variable.starred,
+            });
+        }
+
+        abilities
+    }
+}
+
+fn get_hit1(combined: &CombinedEnemyData) -> AttackHit {
+    let (fixed, variable) = combined;
+    AttackHit {
+        active_ability: bool(variable.proc_on_hit1).unwrap(),
+        damage: fixed.atk,
+        range: AttackRange::new(fixed.ld_base, fixed.ld_range),
+        foreswing: fixed.foreswing,
+    }
+}
+
+fn get_hit2(combined: &CombinedEnemyData) -> AttackHit {
+    let (_, variable) = combined;
+    let range = if bool(variable.second_ld_is_different).unwrap() {
+        AttackRange::new(variable.second_ld_base, variable.second_ld_range)
+    } else {
+        AttackRange::Unchanged
+    };
+
+    AttackHit {
+        active_ability: bool(variable.proc_on_hit2).unwrap(),
+        damage: variable.mhit_atk2,
+        range,
+        foreswing: variable.mhit_atk2_fswing,
+    }
+}
+
+fn get_hit3(combined: &CombinedEnemyData) -> AttackHit {
+    let (_, variable) = combined;
+    let range = if bool(variable.third_ld_is_different).unwrap() {
+        AttackRange::new(variable.third_ld_base, variable.third_ld_range)
+    } else {
+        AttackRange::Unchanged
+    };
+
+    AttackHit {
+        active_ability: bool(variable.proc_on_hit3).unwrap(),
+        damage: variable.mhit_atk3,
+        range,
+        foreswing: variable.mhit_atk3_fswing,
+    }
+}
+
+fn get_hits(combined: &CombinedEnemyData) -> AttackHits {
+    let (_, var) = combined;
+    if var.mhit_atk2 == 0 {
+        let mut hit = get_hit1(combined);
+        hit.active_ability = true;
+        AttackHits::Single([hit])
+    } else if var.mhit_atk3 == 0 {
+        AttackHits::Double([get_hit1(combined), get_hit2(combined)])
+    } else {
+        AttackHits::Triple([get_hit1(combined), get_hit2(combined), get_hit3(combined)])
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+/// Enemy's attack.
+pub struct EnemyAttack {
+    /// All hits of the enemy's attack.
+    pub hits: AttackHits,
+    /// Attack area of effect.
+    pub aoe: AreaOfEffect,
+    /// Standing range before attack.
+    pub standing_range: u16,
+    /// Min cooldown between attacks.
+    pub cooldown: u16,
+}
+impl EnemyAttack {
+    fn from_combined(combined: &CombinedEnemyData) -> Self {
+        let (fixed, _) = combined;
+        let aoe = match fixed.is_area {
+            0 => AreaOfEffect::SingleAttack,
+            1 => AreaOfEffect::AreaAttack,
+            x => {
+                log::warn!("Unknown enemy area value {x}, assuming single target.");
+                AreaOfEffect::SingleAttack
+            }
+        };
+        let tba = fixed.tba;
+        let cooldown = if tba == 0 { 0 } else { 2 * tba - 1 };
+
+        Self {
+            hits: get_hits(combined),
+            aoe,
+            standing_range: fixed.range,
+            cooldown,
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+/// Enemy stats at 100% magnification.
+pub struct EnemyStats {
+    /// Enemy HP.
+    pub hp: u32,
+    /// HP knockbacks.
+    pub kb: u16,
+    /// Speed (distance travelled every frame).
+    pub speed: u8,
+    /// Enemy attack.
+    pub attack: EnemyAttack,
+    /// Money dropped when defeated, before treasure bonuses.
+    pub money_drop: u32,
+    /// Abilities shared with cats.
+    pub abilities: Vec<Ability>,
+    /// Abilities only enemies can have.
+    pub enemy_abilities: Vec<EnemyAbility>,
+    /// Enemy's traits.
+    pub traits: Vec<EnemyType>,
+    /// Enemy's subtypes (witch, colossus etc.).
+    pub subtypes: Vec<EnemySubtype>,
+}
+impl EnemyStats {
+    /// Get enemy stats from the combined stat data.
+    pub fn from_combined(combined: &CombinedEnemyData) -> Self {
+        let (fixed, _) = combined;
+        Self {
+            hp: fixed.hp,
+            kb: fixed.kb,
+            speed: fixed.speed,
+            attack: EnemyAttack::from_combined(combined),
+            money_drop: fixed.money_drop,
+            abilities: Ability::get_all_enemy_abilities(combined),
+            enemy_abilities: EnemyAbility::get_all_abilities(combined),
+            traits: get_enemy_traits(combined),
+            subtypes: get_enemy_subtypes(combined),
+        }
+    }
+
+    /// Get enemy HP at `magnification`% (100 = base stats).
+    pub fn hp_at_magnification(&self, magnification: u32) -> u64 {
+        u64::from(self.hp) * u64::from(magnification) / 100
+    }
+
+    /// Get total damage of the enemy's attack at `magnification`% (100 = base
+    /// stats).
+    pub fn ap_at_magnification(&self, magnification: u32) -> u64 {
+        self.attack
+            .hits
+            .iter()
+            .map(|hit| u64::from(hit.damage) * u64::from(magnification) / 100)
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn magnification() {
+        let mut combined = CombinedEnemyData::default();
+        combined.0.hp = 90;
+        combined.0.atk = 8;
+        let stats = EnemyStats::from_combined(&combined);
+
+        assert_eq!(stats.hp_at_magnification(100), 90);
+        assert_eq!(stats.hp_at_magnification(150), 135);
+        assert_eq!(stats.ap_at_magnification(150), 12);
+        assert_eq!(stats.ap_at_magnification(3_000), 240);
+    }
+
+    #[test]
+    fn unknown_area() {
+        let mut combined = CombinedEnemyData::default();
+        combined.0.is_area = 1;
+        assert_eq!(
+            EnemyAttack::from_combined(&combined).aoe,
+            AreaOfEffect::AreaAttack
+        );
+        combined.0.is_area = 2;
+        assert_eq!(
+            EnemyAttack::from_combined(&combined).aoe,
+            AreaOfEffect::SingleAttack
+        );
+    }
+}
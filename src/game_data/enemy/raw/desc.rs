@@ -0,0 +1,118 @@
+//! Enemy names and descriptions.
+
+use crate::game_data::version::{
+    Version,
+    lang::VersionLanguage,
+    version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+};
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone)]
+/// Description of the enemy.
+pub struct EnemyDescription {
+    name: String,
+    lines: Vec<String>,
+}
+impl EnemyDescription {
+    /// Enemy's in-game name.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Lines of the description, joined by `<br>`s.
+    pub fn lines(&self) -> String {
+        self.lines
+            .iter()
+            .filter_map(|line| {
+                let l = line.trim();
+                if l.is_empty() { None } else { Some(l) }
+            })
+            .collect::<Vec<_>>()
+            .join("<br>")
+    }
+}
+
+#[derive(Debug, thiserror::Error)]
+/// Error when reading enemy descriptions.
+pub enum EnemyDescError {
+    /// Couldn't open file.
+    #[error("couldn't open {0}: {1}")]
+    FileOpenError(String, std::io::Error),
+    /// Couldn't read a line of the file.
+    #[error("couldn't read line {1} of {0}: {2}")]
+    ReadLineError(String, usize, std::io::Error),
+}
+
+fn read_lines(file_name: &str, version: &Version) -> Result<Vec<String>, EnemyDescError> {
+    type E = EnemyDescError;
+    let path = version.get_file_path("resLocal").join(file_name);
+    let file = File::open(path).map_err(|e| E::FileOpenError(file_name.to_string(), e))?;
+
+    BufReader::new(file)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| line.map_err(|e| E::ReadLineError(file_name.to_string(), i, e)))
+        .collect()
+}
+
+#[derive(Debug, Default)]
+/// Container for all enemy descriptions in a version.
+pub struct EnemyDescriptions {
+    /// Doge = 0.
+    descs: Vec<EnemyDescription>,
+}
+impl EnemyDescriptions {
+    /// Get the description of the enemy with wiki id `wiki_id` (Doge = 0).
+    pub fn get_desc(&self, wiki_id: u32) -> Option<&EnemyDescription> {
+        self.descs.get(wiki_id as usize)
+    }
+}
+impl CacheableVersionData for EnemyDescriptions {
+    fn create(version: &Version) -> CvdResult<Self> {
+        let delimiter = match version.language() {
+            VersionLanguage::EN | VersionLanguage::KR | VersionLanguage::TW => '|',
+            VersionLanguage::JP => ',',
+            VersionLanguage::Fallback => {
+                return Err(CvdCreateError::as_default(
+                    "fallback version has no enemy descriptions".into(),
+                ));
+            }
+        };
+
+        let names =
+            read_lines("Enemyname.tsv", version).map_err(CvdCreateError::default_from_err)?;
+        let desc_file = format!("EnemyPictureBook_{lang}.csv", lang = version.language());
+        let desc_lines =
+            read_lines(&desc_file, version).map_err(CvdCreateError::default_from_err)?;
+
+        let descs = names
+            .into_iter()
+            .zip(desc_lines)
+            .map(|(name, desc)| EnemyDescription {
+                name: name.trim().to_string(),
+                lines: desc.split(delimiter).map(String::from).collect(),
+            })
+            .collect();
+
+        Ok(Self { descs })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn doge_en() {
+        let descs = TEST_CONFIG
+            .version
+            .en()
+            .get_cached_file::<EnemyDescriptions>();
+        let doge = descs.get_desc(0).unwrap();
+        assert_eq!(doge.name(), "Doge");
+    }
+}
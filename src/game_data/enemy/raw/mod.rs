@@ -0,0 +1,4 @@
+//! Module for raw enemy data.
+
+pub mod desc;
+pub mod stats;
@@ -0,0 +1,312 @@
+//! Deals with raw CSV enemy data (`t_unit.csv`).
+
+use crate::game_data::version::{
+    Version,
+    version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+};
+use csv::ByteRecord;
+use std::{
+    fs::File,
+    io::{BufRead, BufReader},
+};
+
+/// Could reasonably go above 65,535 (e.g. HP).
+type Massive = u32;
+/// Big number from 0 to 65,535.
+type Big = u16;
+/// 0-100.
+type Percent = u8;
+/// 0-256.
+type Small = u8;
+/// 0 or 1.
+type Bool = u8;
+
+/// CSV data about an enemy.
+pub type CombinedEnemyData = (EnemyCSV, EnemyCSV2);
+
+#[derive(Debug, serde::Deserialize, Default, Clone)]
+#[allow(missing_docs)]
+/// Fixed CSV data. Field meanings are mostly taken from BCU.
+pub struct EnemyCSV {
+    pub hp: Massive,
+    pub kb: Big,
+    pub speed: Small,
+    pub atk: Massive,
+    /// Time between attacks / 2.
+    pub tba: Big,
+    pub range: Big,
+    /// Base money drop, before any treasure bonuses are applied.
+    pub money_drop: Massive,
+    /// Always 0.
+    _uk7: Small,
+    /// Unit width.
+    _width: Big,
+    /// Always 0.
+    _uk9: Small,
+
+    // 10
+    pub is_red: Bool,
+    pub is_area: Bool,
+    pub foreswing: Big,
+    pub is_floating: Bool,
+    pub is_black: Bool,
+    pub is_metal: Bool,
+    pub is_traitless: Bool,
+    pub is_angel: Bool,
+    pub is_alien: Bool,
+    pub is_zombie: Bool,
+
+    // 20
+    pub kb_chance: Percent,
+    pub freeze_chance: Percent,
+    pub freeze_duration: Big,
+    pub slow_chance: Percent,
+    pub slow_duration: Big,
+    pub crit_chance: Percent,
+    pub has_base_destroyer: Bool,
+    pub wave_chance: Percent,
+    pub wave_level: Small,
+    pub weaken_chance: Percent,
+
+    // 30
+    pub weaken_duration: Big,
+    pub weaken_multiplier: Percent,
+    pub strengthen_hp: Percent,
+    pub strengthen_multiplier: Big,
+    pub survives_chance: Percent,
+    pub ld_base: i16,
+    pub ld_range: i16,
+    pub immune_wave: Bool,
+    pub has_wave_blocker: Bool,
+    pub immune_kb: Bool,
+
+    // 40
+    pub immune_freeze: Bool,
+    pub immune_slow: Bool,
+    pub immune_weaken: Bool,
+    /// Amount of times the enemy can burrow (-1 is infinite).
+    pub burrow_amount: i8,
+    /// Burrow distance * 4.
+    pub burrow_distance_quad: Big,
+    /// Amount of times the enemy can revive (-1 is infinite).
+    pub revive_amount: i8,
+    pub revive_time: Big,
+    pub revive_hp: Big,
+    pub is_witch: Bool,
+    /// "Is base". Always 0 in actual enemy data.
+    _uk49: Small,
+
+    // 50
+    /// "Attacks left" or "loop" according to BCU.
+    _uk50: i8,
+    /// Always 0.
+    _uk51: Small,
+}
+
+#[derive(Debug, Default, serde::Deserialize, Clone)]
+#[serde(default)]
+#[allow(missing_docs)]
+/// Data that may not exist. All fields default to `0` if not explicitly given.
+pub struct EnemyCSV2 {
+    // index = 52
+    /// "Time before death" according to BCU.
+    _uk52: i8,
+    /// Always 0.
+    _uk53: Small,
+    /// Death animation id. -1 means no soul.
+    pub death: i8,
+    pub mhit_atk2: Massive,
+    pub mhit_atk3: Massive,
+    pub mhit_atk2_fswing: Big,
+    pub mhit_atk3_fswing: Big,
+    pub proc_on_hit1: Bool,
+
+    // 60
+    pub proc_on_hit2: Bool,
+    pub proc_on_hit3: Bool,
+    /// "Spawn animation" according to BCU.
+    _uk62: i8,
+    /// "Soul animation" according to BCU.
+    _uk63: i8,
+    pub barrier_hp: Massive,
+    pub warp_chance: Percent,
+    pub warp_duration: Big,
+    /// Min warp distance * 4.
+    pub warp_min_quad: i32,
+    /// Max warp distance * 4.
+    pub warp_max_quad: i32,
+    /// Amount of stars on a starred alien.
+    pub starred: Small,
+
+    // 70
+    /// Always 0.
+    _uk70: Small,
+    pub is_eva_angel: Bool,
+    pub is_relic: Bool,
+    pub curse_chance: Percent,
+    pub curse_duration: Big,
+    pub savage_blow_chance: Percent,
+    pub savage_blow_percent: Big,
+    pub dodge_chance: Percent,
+    pub dodge_duration: Big,
+    pub toxic_chance: Percent,
+
+    // 80
+    pub toxic_percent: Percent,
+    pub surge_chance: Percent,
+    /// Same as cat value, i.e. 4 * actual range.
+    pub surge_spawn_quad: Big,
+    /// Same as cat value, i.e. 4 * actual range.
+    pub surge_range_quad: Big,
+    pub surge_level: Small,
+    pub is_aku: Bool,
+    pub shield_hp: Massive,
+    pub shield_regen_percent: Big,
+    pub death_surge_chance: Percent,
+    pub death_surge_spawn_quad: Big,
+
+    // 90
+    pub death_surge_range_quad: Big,
+    pub death_surge_level: Small,
+    pub is_colossus: Bool,
+    pub is_mini_wave: Bool,
+    pub second_ld_is_different: Bool,
+    pub second_ld_base: i16,
+    pub second_ld_range: i16,
+    pub third_ld_is_different: Bool,
+    pub third_ld_base: i16,
+    pub third_ld_range: i16,
+
+    // 100
+    pub is_behemoth: Bool,
+    pub is_mini_surge: Bool,
+    pub has_counter_surge: Bool,
+    pub is_sage: Bool,
+    pub is_supervillain: Bool,
+    pub explosion_chance: Percent,
+    /// Same as cat value, i.e. 4 * actual range.
+    pub explosion_spawn_quad: Big,
+
+    rest: Vec<i32>,
+}
+
+fn read_enemy_line(line: &str) -> Result<CombinedEnemyData, csv::Error> {
+    const FIXED_LEN: usize = 52;
+    let record = line.split(',').collect::<ByteRecord>();
+
+    let fixed: EnemyCSV = record
+        .iter()
+        .take(FIXED_LEN)
+        .collect::<ByteRecord>()
+        .deserialize(None)?;
+    let var: EnemyCSV2 = record
+        .iter()
+        .skip(FIXED_LEN)
+        .collect::<ByteRecord>()
+        .deserialize(None)?;
+
+    Ok((fixed, var))
+}
+
+#[derive(Debug, thiserror::Error)]
+/// Error when reading `t_unit.csv`.
+pub enum EnemyStatsError {
+    /// Couldn't open file.
+    #[error("couldn't open t_unit.csv: {0}")]
+    FileOpenError(std::io::Error),
+    /// Couldn't read a line of the file.
+    #[error("couldn't read line {0} of t_unit.csv: {1}")]
+    ReadLineError(usize, std::io::Error),
+    /// Couldn't deserialise a line of the file.
+    #[error("couldn't parse line {0} of t_unit.csv: {1}")]
+    ParseError(usize, csv::Error),
+}
+
+fn get_t_unit(version: &Version) -> Result<Vec<CombinedEnemyData>, EnemyStatsError> {
+    type E = EnemyStatsError;
+    let path = version.get_file_path("DataLocal/t_unit.csv");
+    let reader = BufReader::new(File::open(path).map_err(E::FileOpenError)?);
+
+    let mut enemies = vec![];
+    for (i, line) in reader.lines().enumerate() {
+        let line = line.map_err(|e| E::ReadLineError(i, e))?;
+        let line = line
+            .split("//")
+            .next()
+            .expect("Shouldn't panic on first next.")
+            .trim_matches(|c: char| c.is_whitespace() || c == ',');
+
+        if line.is_empty() {
+            continue;
+        }
+        enemies.push(read_enemy_line(line).map_err(|e| E::ParseError(i, e))?);
+    }
+
+    Ok(enemies)
+}
+
+#[derive(Debug)]
+/// Container for [`CombinedEnemyData`].
+pub struct EnemyStatsContainer {
+    /// Doge = 0.
+    enemies: Vec<CombinedEnemyData>,
+}
+impl EnemyStatsContainer {
+    /// Get the raw data for the enemy with wiki id `wiki_id` (Doge = 0).
+    pub fn get_enemy(&self, wiki_id: u32) -> Option<&CombinedEnemyData> {
+        self.enemies.get(wiki_id as usize)
+    }
+
+    /// Amount of enemies in the file.
+    pub fn len(&self) -> usize {
+        self.enemies.len()
+    }
+
+    /// Is the container empty.
+    pub fn is_empty(&self) -> bool {
+        self.enemies.is_empty()
+    }
+}
+impl CacheableVersionData for EnemyStatsContainer {
+    fn create(version: &Version) -> CvdResult<Self> {
+        let enemies = get_t_unit(version).map_err(CvdCreateError::throw_from_err)?;
+        Ok(Self { enemies })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn test_file_reader() {
+        let version = TEST_CONFIG.version.current_version();
+        let container = version.get_cached_file::<EnemyStatsContainer>();
+        assert!(!container.is_empty());
+
+        for (fixed, var) in &container.enemies {
+            assert_eq!(fixed._uk49, 0);
+            assert!(
+                var.rest.is_empty(),
+                "Remaining fields not empty, found {:?}",
+                var.rest
+            );
+        }
+    }
+
+    #[test]
+    fn test_doge() {
+        let version = TEST_CONFIG.version.current_version();
+        let (doge, _) = version
+            .get_cached_file::<EnemyStatsContainer>()
+            .get_enemy(0)
+            .unwrap();
+
+        assert_eq!(doge.hp, 90);
+        assert_eq!(doge.kb, 3);
+        assert_eq!(doge.speed, 5);
+        assert_eq!(doge.atk, 8);
+        assert_eq!(doge.range, 110);
+    }
+}
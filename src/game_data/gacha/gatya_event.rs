@@ -0,0 +1,242 @@
+//! Module that deals with the gacha event data file (`gatya.tsv`).
+//!
+//! This file isn't part of the game's files and comes from the event data
+//! server instead. Each line is a single event and is tab-separated:
+//!
+//! - Start date (`YYYYMMDD`), start time (`HHMM`), end date and end time.
+//! - Minimum and maximum game version.
+//! - Two unused columns.
+//! - Gacha type (1 = Rare Cat Capsule) and the amount of gachas in the event.
+//! - 15 columns for each gacha: set id, two unused columns, flags (4 =
+//!   step-up), then the rate (out of 10,000) and guaranteed flag of Normal,
+//!   Rare, Super Rare, Uber Rare and Legend Rare cats, and finally the banner's
+//!   message.
+
+use crate::game_data::cat::parsed::unitbuy::Rarity;
+use serde::Serialize;
+use std::{error::Error, fmt::Display, fs::read_to_string, path::Path};
+
+/// Amount of columns before the first gacha.
+const HEADER_LEN: usize = 10;
+/// Amount of columns each gacha takes up.
+const GACHA_LEN: usize = 15;
+/// Gacha type of Rare Cat Capsule events.
+pub const RARE_GACHA_TYPE: u32 = 1;
+/// Flag for step-up gachas.
+const STEP_UP_FLAG: u32 = 4;
+
+/// Rarities in the order they appear in the file.
+pub const RATE_RARITIES: [Rarity; 5] = [
+    Rarity::Normal,
+    Rarity::Rare,
+    Rarity::SuperRare,
+    Rarity::UberRare,
+    Rarity::LegendRare,
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// Date and time in the event data.
+pub struct GatyaDate {
+    /// Date as `YYYYMMDD`.
+    pub date: u32,
+    /// Time as `HHMM`.
+    pub time: u32,
+}
+impl GatyaDate {
+    const fn year(&self) -> u32 {
+        self.date / 10_000
+    }
+    const fn month(&self) -> u32 {
+        self.date / 100 % 100
+    }
+    const fn day(&self) -> u32 {
+        self.date % 100
+    }
+}
+impl Display for GatyaDate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        const MONTHS: [&str; 12] = [
+            "January",
+            "February",
+            "March",
+            "April",
+            "May",
+            "June",
+            "July",
+            "August",
+            "September",
+            "October",
+            "November",
+            "December",
+        ];
+        let month = MONTHS
+            .get(self.month().wrapping_sub(1) as usize)
+            .unwrap_or(&"?");
+        write!(
+            f,
+            "{month} {day}, {year}",
+            day = self.day(),
+            year = self.year()
+        )
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// Rate of a single rarity.
+pub struct GatyaRate {
+    /// Rarity of the cats.
+    pub rarity: Rarity,
+    /// Rate out of 10,000.
+    pub rate: u32,
+    /// Is a cat of this rarity guaranteed on an 11-draw.
+    pub guaranteed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Single gacha in an event.
+pub struct GatyaEventGacha {
+    /// Gacha set id.
+    pub id: u32,
+    /// Is the gacha a step-up gacha.
+    pub step_up: bool,
+    /// Rates of each rarity.
+    pub rates: [GatyaRate; 5],
+    /// Banner message.
+    pub message: String,
+}
+impl GatyaEventGacha {
+    /// Parse the columns of a single gacha.
+    fn from_record(record: &[&str]) -> Result<Self, Box<dyn Error>> {
+        let num = |i: usize| record[i].trim().parse::<u32>();
+
+        let mut rates = [GatyaRate {
+            rarity: Rarity::Normal,
+            rate: 0,
+            guaranteed: false,
+        }; 5];
+        for (i, (rate, rarity)) in rates.iter_mut().zip(RATE_RARITIES).enumerate() {
+            *rate = GatyaRate {
+                rarity,
+                rate: num(4 + 2 * i)?,
+                guaranteed: num(5 + 2 * i)? != 0,
+            };
+        }
+
+        Ok(Self {
+            id: num(0)?,
+            step_up: num(3)? & STEP_UP_FLAG != 0,
+            rates,
+            message: record[14].trim().to_string(),
+        })
+    }
+
+    /// Is any rarity guaranteed.
+    pub fn is_guaranteed(&self) -> bool {
+        self.rates.iter().any(|rate| rate.guaranteed)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Single line of the event data.
+pub struct GatyaEvent {
+    /// When the event starts.
+    pub start: GatyaDate,
+    /// When the event ends.
+    pub end: GatyaDate,
+    /// Minimum game version.
+    pub min_version: u32,
+    /// Maximum game version.
+    pub max_version: u32,
+    /// Type of gacha.
+    pub gacha_type: u32,
+    /// Gachas available during the event.
+    pub gachas: Vec<GatyaEventGacha>,
+}
+impl GatyaEvent {
+    /// Is the event for Rare Cat Capsules (i.e. its gachas use
+    /// `GatyaDataSetR1`).
+    pub const fn is_rare_capsule(&self) -> bool {
+        self.gacha_type == RARE_GACHA_TYPE
+    }
+
+    /// Parse a single line of the file.
+    fn from_line(line: &str) -> Result<Self, Box<dyn Error>> {
+        let record = line.split('\t').collect::<Vec<_>>();
+        if record.len() < HEADER_LEN {
+            return Err(format!("Gacha event line is too short: {line:?}").into());
+        }
+        let num = |i: usize| record[i].trim().parse::<u32>();
+
+        let amt_gachas = num(9)? as usize;
+        let end = HEADER_LEN + amt_gachas * GACHA_LEN;
+        if record.len() < end {
+            return Err(format!("Gacha event line has incomplete gachas: {line:?}").into());
+        }
+        let gachas = record[HEADER_LEN..end]
+            .chunks_exact(GACHA_LEN)
+            .map(GatyaEventGacha::from_record)
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            start: GatyaDate {
+                date: num(0)?,
+                time: num(1)?,
+            },
+            end: GatyaDate {
+                date: num(2)?,
+                time: num(3)?,
+            },
+            min_version: num(4)?,
+            max_version: num(5)?,
+            gacha_type: num(8)?,
+            gachas,
+        })
+    }
+}
+
+/// Read every event in the gacha event data file at `path`.
+pub fn read_gatya_events(path: &Path) -> Result<Vec<GatyaEvent>, Box<dyn Error>> {
+    read_to_string(path)?
+        .lines()
+        .filter(|line| !line.trim().is_empty() && !line.starts_with('['))
+        .map(GatyaEvent::from_line)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const LINE: &str = "20240101\t1100\t20240108\t1059\t130000\t999999\t0\t0\t1\t1\t\
+        602\t0\t0\t4\t0\t0\t7000\t0\t2500\t0\t450\t1\t50\t0\tNew Year's Gacha";
+
+    #[test]
+    fn parse_line() {
+        let event = GatyaEvent::from_line(LINE).unwrap();
+        assert_eq!(event.start.to_string(), "January 1, 2024");
+        assert_eq!(event.end.date, 20240108);
+        assert_eq!(event.min_version, 130000);
+        assert!(event.is_rare_capsule());
+        assert_eq!(event.gachas.len(), 1);
+
+        let gacha = &event.gachas[0];
+        assert_eq!(gacha.id, 602);
+        assert!(gacha.step_up);
+        assert!(gacha.is_guaranteed());
+        assert_eq!(gacha.message, "New Year's Gacha");
+        assert_eq!(
+            gacha.rates[3],
+            GatyaRate {
+                rarity: Rarity::UberRare,
+                rate: 450,
+                guaranteed: true
+            }
+        );
+    }
+
+    #[test]
+    fn incomplete_line() {
+        assert!(GatyaEvent::from_line("20240101\t1100").is_err());
+        assert!(GatyaEvent::from_line(&LINE.replace("\t1\t1\t", "\t1\t2\t")).is_err());
+    }
+}
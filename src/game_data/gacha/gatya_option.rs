@@ -0,0 +1,98 @@
+//! Module that deals with the `GatyaData_Option_SetR` file.
+//!
+//! After the header line, each line is tab-separated and starts with the gacha
+//! set's id, whether the banner is shown and the set's series id. Other columns
+//! aren't used.
+
+use crate::game_data::version::{
+    Version,
+    version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+};
+use serde::Serialize;
+use std::{collections::HashMap, error::Error, fs::File};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Display options of a gacha set.
+pub struct GatyaOption {
+    /// Gacha set id.
+    pub id: u32,
+    /// Is the banner shown in game.
+    pub banner_on: bool,
+    /// Id of the series the set belongs to, or -1 if it isn't part of one.
+    pub series_id: i32,
+}
+impl GatyaOption {
+    /// Parse a single line of the file.
+    fn from_record(record: &[&str]) -> Result<Self, Box<dyn Error>> {
+        let [id, banner_on, _, series_id, ..] = record else {
+            return Err(format!("Gacha option line is too short: {record:?}").into());
+        };
+
+        Ok(Self {
+            id: id.trim().parse()?,
+            banner_on: banner_on.trim().parse::<u8>()? != 0,
+            series_id: series_id.trim().parse()?,
+        })
+    }
+}
+
+fn get_gatya_options(file: File) -> Result<HashMap<u32, GatyaOption>, Box<dyn Error>> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(true)
+        .delimiter(b'\t')
+        .flexible(true)
+        .from_reader(file);
+
+    let mut map = HashMap::new();
+    for record in rdr.records() {
+        let record = record.map_err(Box::new)?;
+        let option = GatyaOption::from_record(&record.iter().collect::<Vec<_>>())?;
+        map.insert(option.id, option);
+    }
+
+    Ok(map)
+}
+
+#[derive(Debug, Default)]
+/// Container for gacha set options.
+pub struct GatyaOptions {
+    map: HashMap<u32, GatyaOption>,
+}
+impl GatyaOptions {
+    /// Get the options of the gacha set.
+    pub fn get_option(&self, id: u32) -> Option<&GatyaOption> {
+        self.map.get(&id)
+    }
+}
+impl CacheableVersionData for GatyaOptions {
+    fn create(version: &Version) -> CvdResult<Self> {
+        let file = File::open(
+            version
+                .location()
+                .join("DataLocal/GatyaData_Option_SetR.tsv"),
+        )
+        .map_err(CvdCreateError::default_from_err)?;
+        Ok(Self {
+            map: get_gatya_options(file).map_err(CvdCreateError::throw)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line() {
+        let option = GatyaOption::from_record(&["602", "1", "0", "5", "0"]).unwrap();
+        assert_eq!(
+            option,
+            GatyaOption {
+                id: 602,
+                banner_on: true,
+                series_id: 5
+            }
+        );
+        assert!(GatyaOption::from_record(&["602", "1"]).is_err());
+    }
+}
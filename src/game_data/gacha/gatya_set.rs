@@ -0,0 +1,78 @@
+//! Module that deals with the `GatyaDataSetR1` file.
+//!
+//! Each line is a single gacha set, so the line number is the set's id. A line
+//! lists the ids of every cat in the set and ends with -1. Cats that appear
+//! more than once have their rates increased.
+
+use crate::game_data::version::{
+    Version,
+    version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+};
+use std::{
+    error::Error,
+    fs::File,
+    io::{BufRead, BufReader},
+    num::ParseIntError,
+};
+
+/// Parse a single line of the file.
+fn parse_set(line: &str) -> Result<Vec<u32>, ParseIntError> {
+    let mut cats = vec![];
+    for value in line.split(',').map(str::trim) {
+        if value.is_empty() {
+            continue;
+        }
+        let id = value.parse::<i32>()?;
+        let Ok(id) = u32::try_from(id) else {
+            break;
+        };
+        cats.push(id);
+    }
+
+    Ok(cats)
+}
+
+fn get_gatya_sets(reader: impl BufRead) -> Result<Vec<Vec<u32>>, Box<dyn Error>> {
+    let mut sets = vec![];
+    for line in reader.lines() {
+        let line = line?;
+        if line.starts_with("//") {
+            continue;
+        }
+        sets.push(parse_set(&line)?);
+    }
+    Ok(sets)
+}
+
+#[derive(Debug, Default)]
+/// Cats in each Rare Cat Capsule gacha set.
+pub struct GatyaSets {
+    sets: Vec<Vec<u32>>,
+}
+impl GatyaSets {
+    /// Get the ids of every cat in the set, including duplicates.
+    pub fn get_set(&self, id: u32) -> Option<&[u32]> {
+        self.sets.get(id as usize).map(Vec::as_slice)
+    }
+}
+impl CacheableVersionData for GatyaSets {
+    fn create(version: &Version) -> CvdResult<Self> {
+        let file = File::open(version.location().join("DataLocal/GatyaDataSetR1.csv"))
+            .map_err(CvdCreateError::default_from_err)?;
+        Ok(Self {
+            sets: get_gatya_sets(BufReader::new(file)).map_err(CvdCreateError::throw)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_line() {
+        assert_eq!(parse_set("34,35,36,34,-1,").unwrap(), [34, 35, 36, 34]);
+        assert_eq!(parse_set("-1").unwrap(), [] as [u32; 0]);
+        assert!(parse_set("34,a,-1").is_err());
+    }
+}
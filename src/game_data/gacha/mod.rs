@@ -0,0 +1,5 @@
+//! Deals with gacha banners.
+
+pub mod gatya_event;
+pub mod gatya_option;
+pub mod gatya_set;
@@ -0,0 +1,115 @@
+//! Module that deals with the `Gatyaitembuy` and `GatyaitemName` files.
+//!
+//! Each line of `Gatyaitembuy.csv` is a single item, so the line number is
+//! the item's id. Only the amount of lines is used, since the file's columns
+//! aren't needed for item names. `GatyaitemName.csv` is in the same order, with
+//! each line containing the item's name and then its description.
+
+use crate::game_data::version::{
+    Version,
+    lang::VersionLanguage,
+    version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+};
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader},
+};
+
+/// Get the amount of items in `Gatyaitembuy.csv`.
+fn count_items(reader: impl BufRead) -> io::Result<usize> {
+    let mut count = 0;
+    for line in reader.lines() {
+        if !line?.trim().is_empty() {
+            count += 1;
+        }
+    }
+    Ok(count)
+}
+
+/// Get the item's name from a line of `GatyaitemName.csv`.
+fn parse_name(line: &str, delimiter: char) -> Option<String> {
+    let name = line.split(delimiter).next()?.trim();
+    if name.is_empty() || name == "＠" {
+        None
+    } else {
+        Some(name.to_string())
+    }
+}
+
+/// Get the names of every item in `GatyaitemName.csv`.
+fn get_names(reader: impl BufRead, delimiter: char) -> io::Result<Vec<Option<String>>> {
+    reader
+        .lines()
+        .map(|line| Ok(parse_name(&line?, delimiter)))
+        .collect()
+}
+
+#[derive(Debug, Default, Clone)]
+/// Names of all items that can be obtained in game.
+pub struct GatyaItems {
+    names: Vec<Option<String>>,
+}
+impl GatyaItems {
+    /// Get the in-game name of the item.
+    pub fn name(&self, id: u32) -> Option<&str> {
+        self.names.get(id as usize)?.as_deref()
+    }
+
+    /// Iterate over the ids of all items.
+    pub fn ids(&self) -> impl Iterator<Item = u32> {
+        0..self.names.len() as u32
+    }
+}
+impl CacheableVersionData for GatyaItems {
+    fn create(version: &Version) -> CvdResult<Self> {
+        let delimiter = match version.language() {
+            VersionLanguage::EN | VersionLanguage::KR | VersionLanguage::TW => '|',
+            VersionLanguage::JP => ',',
+            VersionLanguage::Fallback => {
+                return Err(CvdCreateError::as_default(
+                    "fallback version has no item names".into(),
+                ));
+            }
+        };
+
+        let buy = File::open(version.get_file_path("DataLocal").join("Gatyaitembuy.csv"))
+            .map_err(CvdCreateError::default_from_err)?;
+        let amt_items = count_items(BufReader::new(buy)).map_err(CvdCreateError::throw_from_err)?;
+
+        let names = File::open(version.get_file_path("resLocal").join("GatyaitemName.csv"))
+            .map_err(CvdCreateError::default_from_err)?;
+        let mut names =
+            get_names(BufReader::new(names), delimiter).map_err(CvdCreateError::throw_from_err)?;
+        names.resize(amt_items, None);
+
+        Ok(Self { names })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_names() {
+        assert_eq!(
+            parse_name("Speed Up|Doubles the speed of battle", '|'),
+            Some("Speed Up".to_string())
+        );
+        assert_eq!(
+            parse_name("スピードアップ,説明", ','),
+            Some("スピードアップ".to_string())
+        );
+        assert_eq!(parse_name("＠|", '|'), None);
+        assert_eq!(parse_name("", '|'), None);
+    }
+
+    #[test]
+    fn read_files() {
+        assert_eq!(count_items("0,1,2\n3,4,5\n\n".as_bytes()).unwrap(), 2);
+        assert_eq!(
+            get_names("Speed Up|desc\n＠|\n".as_bytes(), '|').unwrap(),
+            [Some("Speed Up".to_string()), None]
+        );
+    }
+}
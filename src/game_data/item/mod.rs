@@ -0,0 +1,3 @@
+//! Deals with items (e.g. Cat Food, Speed Up, Catamins).
+
+pub mod gatya_item;
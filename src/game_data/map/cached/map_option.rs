@@ -10,7 +10,7 @@ use crate::game_data::{
 use csv::ByteRecord;
 use std::{collections::HashMap, error::Error, num::NonZero, path::Path};
 
-#[derive(Debug, serde::Deserialize, Clone)]
+#[derive(Debug, serde::Deserialize, serde::Serialize, Clone)]
 /// Data stored in the map option CSV.
 pub struct MapOptionCSV {
     /// Map's mapid.
@@ -109,6 +109,11 @@ impl MapOption {
     pub fn get_map(&self, map_id: &MapID) -> Option<&MapOptionCSV> {
         Some(self.map.get_key_value(&map_id.mapid())?.1)
     }
+
+    /// Iterate over all maps' option data, with their mapids.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &MapOptionCSV)> {
+        self.map.iter().map(|(mapid, data)| (*mapid, data))
+    }
 }
 
 #[cfg(test)]
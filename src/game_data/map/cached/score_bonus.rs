@@ -8,6 +8,7 @@ use crate::game_data::{
     },
 };
 use raw::{BonusesMap, RawBonusData, RawBonusType};
+use serde::Serialize;
 use std::{collections::HashMap, fs::File};
 
 /// Size of numeric parameters to bonuses.
@@ -46,7 +47,7 @@ mod raw {
 type Single = [ParamSize; 1];
 
 /// Type of score bonus.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub enum BonusType {
     /// Parameter is base points for one target.
     Weaken(Single),
@@ -91,7 +92,7 @@ impl BonusType {
     }
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Possible bonus name label value.
 pub enum BonusNameLabel {
     /// Weaken.
@@ -140,7 +141,7 @@ impl BonusNameLabel {
 }
 
 /// Represents all score bonuses for an individual map.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct ScoreBonus {
     /// All of the map's bonuses.
     pub bonus_type: Vec<BonusType>,
@@ -174,6 +175,11 @@ impl ScoreBonuses {
     pub fn get_map(&self, map_id: &MapID) -> Option<&ScoreBonus> {
         self.map.get(&map_id.mapid())
     }
+
+    /// Iterate over all maps' score bonuses, with their mapids.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &ScoreBonus)> {
+        self.map.iter().map(|(mapid, data)| (*mapid, data))
+    }
 }
 impl From<BonusesMap> for ScoreBonuses {
     fn from(value: BonusesMap) -> Self {
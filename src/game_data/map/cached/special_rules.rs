@@ -8,6 +8,7 @@ use crate::game_data::{
     },
 };
 use raw::{RawRuleData, RawRuleType, RulesMap};
+use serde::Serialize;
 use std::{collections::HashMap, fs::File};
 use strum::FromRepr;
 
@@ -48,7 +49,7 @@ mod raw {
 }
 
 #[repr(u8)]
-#[derive(Debug, Clone, PartialEq, FromRepr)]
+#[derive(Debug, Clone, PartialEq, FromRepr, Serialize)]
 /// Exact meaning is unclear.
 pub enum ContentsType {
     /// Only used in Colosseum stages.
@@ -71,7 +72,7 @@ type Single = [ParamSize; 1];
 type Rarity = [ParamSize; AMT_RARITIES];
 
 /// Type of special rule.
-#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 pub enum RuleType {
     /// Param is starting cash in ¢.
     TrustFund(Single),
@@ -141,7 +142,7 @@ impl RuleType {
 }
 
 // TODO completely remove; fallback should be enough
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Serialize)]
 /// Possible rule name label value.
 pub enum RuleNameLabel {
     /// Trust Fund.
@@ -230,7 +231,7 @@ impl RuleNameLabel {
 }
 
 /// Represents all special rules for an individual map.
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize)]
 pub struct SpecialRule {
     /// Unclear what the purpose is, other than war funds.
     pub contents_type: ContentsType,
@@ -268,6 +269,11 @@ impl SpecialRules {
     pub fn get_map(&self, map_id: &MapID) -> Option<&SpecialRule> {
         self.map.get(&map_id.mapid())
     }
+
+    /// Iterate over all maps' special rules, with their mapids.
+    pub fn iter(&self) -> impl Iterator<Item = (u32, &SpecialRule)> {
+        self.map.iter().map(|(mapid, data)| (*mapid, data))
+    }
 }
 impl From<RulesMap> for SpecialRules {
     fn from(value: RulesMap) -> Self {
@@ -12,11 +12,12 @@ use crate::game_data::{
     stage::parsed::stage::{CrownData, Restriction, RestrictionStages, Stage},
     version::Version,
 };
+use serde::Serialize;
 use std::num::NonZeroU32;
 use strum::FromRepr;
 
 #[repr(u8)]
-#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, FromRepr)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, FromRepr, Serialize)]
 /// What happens when event ends. Event can be ended by reaching max clears or
 /// by the timer running out.
 pub enum ResetType {
@@ -43,7 +44,7 @@ impl From<u8> for ResetType {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Full Version-agnostic owned map struct.
 pub struct GameMap {
     /// ID of map.
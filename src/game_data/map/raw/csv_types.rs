@@ -54,7 +54,7 @@ pub struct StageInfoCSVFixed {
     _second_track: u32,
 }
 
-#[derive(Debug, PartialEq, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 /// CSV data related to stage treasures.
 pub struct TreasureCSV {
     /// Chance the item will drop.
@@ -65,7 +65,7 @@ pub struct TreasureCSV {
     pub item_amt: u32,
 }
 
-#[derive(Debug, PartialEq, serde::Deserialize)]
+#[derive(Debug, PartialEq, serde::Deserialize, serde::Serialize)]
 /// CSV data related to timed score rewards.
 pub struct ScoreRewardsCSV {
     /// Score required to get item.
@@ -77,7 +77,7 @@ pub struct ScoreRewardsCSV {
 }
 
 #[repr(i32)]
-#[derive(Debug, PartialEq, FromRepr)]
+#[derive(Debug, PartialEq, FromRepr, serde::Serialize)]
 /// Treasure drop reward modifier.
 ///
 /// All descriptions are purely speculative based on BCU code; if you have
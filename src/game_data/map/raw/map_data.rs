@@ -15,7 +15,10 @@ use crate::game_data::{
         map_id::MapID, stage_id::StageID, stage_types::transform::transform_map::map_data_file,
         variant::StageVariantID,
     },
-    stage::raw::stage_option::{StageOption, StageOptionCSV},
+    stage::raw::{
+        fixed_formation::{FixedFormationCSV, FixedFormations},
+        stage_option::{StageOption, StageOptionCSV},
+    },
     version::Version,
 };
 use csv::ByteRecord;
@@ -197,6 +200,15 @@ impl GameMapData {
         stage_option.get_stage(stage)
     }
 
+    /// Get fixed_formation data if it exists.
+    pub fn get_fixed_formation_data<'a>(
+        stage: &StageID,
+        version: &'a Version,
+    ) -> Option<&'a FixedFormationCSV> {
+        let fixed_formation = version.get_cached_file::<FixedFormations>();
+        fixed_formation.get_stage(stage)
+    }
+
     /// Get Map_option data if it exists.
     pub fn get_ex_option_data(map: &MapID, version: &Version) -> Option<u32> {
         let ex_option = version.get_cached_file::<ExOption>();
@@ -1,6 +1,7 @@
 //! ID for a stage map.
 
 use super::variant::{StageVariantID, VariantSize};
+use serde::Serialize;
 use std::fmt::Display;
 
 /// Type of main chapter.
@@ -15,7 +16,7 @@ pub enum MainType {
 
 /// Size of map number.
 pub type MapSize = u32;
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 /// Identifies a map.
 pub struct MapID {
     /// Stage type variant.
@@ -4,11 +4,12 @@ use super::{
     map_id::{MapID, MapSize},
     variant::{StageVariantID, VariantSize},
 };
+use serde::Serialize;
 use std::fmt::Display;
 
 /// Size of stage number.
 pub type StageSize = u32;
-#[derive(Debug, PartialEq, Clone)]
+#[derive(Debug, PartialEq, Clone, Serialize)]
 /// Identifies a stage.
 pub struct StageID {
     map: MapID,
@@ -1,6 +1,7 @@
 //! Parse ID from various formats.
 
 pub mod parse_map;
+pub mod parse_range;
 pub mod parse_stage;
 pub mod parse_types;
 mod parse_util;
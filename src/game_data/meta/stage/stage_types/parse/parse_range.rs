@@ -0,0 +1,255 @@
+//! Parse selectors that refer to multiple stages (e.g. `"sol 0 *"`).
+
+use super::{parse_types::StageTypeParseError, parse_util::get_variant_from_code};
+use crate::game_data::meta::stage::{
+    stage_id::StageID, stage_types::data::SELECTOR_SEPARATOR, variant::StageVariantID as T,
+};
+
+/// Matches any number.
+const WILDCARD: &str = "*";
+/// Separates the start and end of a [`NumRange::Between`].
+const RANGE_SEPARATOR: char = '-';
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+/// Range of map or stage numbers.
+pub enum NumRange {
+    /// Any number (`*`).
+    All,
+    /// Numbers from start to end inclusive (`0-5`, or just `3`).
+    Between(u32, u32),
+}
+impl NumRange {
+    /// Does the range contain `num`.
+    pub fn contains(&self, num: u32) -> bool {
+        match self {
+            Self::All => true,
+            Self::Between(start, end) => (*start..=*end).contains(&num),
+        }
+    }
+
+    /// Add `offset` to both ends of the range.
+    fn offset(self, offset: u32) -> Self {
+        match self {
+            Self::All => Self::All,
+            Self::Between(start, end) => Self::Between(start + offset, end + offset),
+        }
+    }
+
+    /// Is `part` a wildcard or `start-end` range, rather than a plain number.
+    fn is_range(part: &str) -> bool {
+        part == WILDCARD
+            || part
+                .split_once(RANGE_SEPARATOR)
+                .is_some_and(|(start, end)| {
+                    [start, end]
+                        .iter()
+                        .all(|n| !n.is_empty() && n.bytes().all(|b| b.is_ascii_digit()))
+                })
+    }
+
+    /// Parse `"*"`, `"0-5"` or `"3"`. If `part` is [`None`] then the range
+    /// matches anything.
+    fn parse(part: Option<&str>) -> Result<Self, StageTypeParseError> {
+        let part = match part {
+            None | Some(WILDCARD) => return Ok(Self::All),
+            Some(part) => part,
+        };
+        let num = |n: &str| {
+            n.parse::<u32>()
+                .map_err(|_| StageTypeParseError::InvalidNumber)
+        };
+
+        let (start, end) = match part.split_once(RANGE_SEPARATOR) {
+            Some((start, end)) => (num(start)?, num(end)?),
+            None => (num(part)?, num(part)?),
+        };
+        if start > end {
+            return Err(StageTypeParseError::InvalidNumber);
+        }
+        Ok(Self::Between(start, end))
+    }
+}
+
+#[derive(Debug, PartialEq)]
+/// Selection of stages from a single stage type.
+pub struct StageRange {
+    /// Stage type.
+    pub variant: T,
+    /// Maps in the selection.
+    pub map: NumRange,
+    /// Stages in each map.
+    pub stage: NumRange,
+}
+impl StageRange {
+    /// Is `id` part of the selection.
+    pub fn contains(&self, id: &StageID) -> bool {
+        id.variant() == self.variant
+            && self.map.contains(id.map().num())
+            && self.stage.contains(id.num())
+    }
+}
+
+/// Does `selector` contain a wildcard or a range (e.g. `"event 200 0-5"`)?
+pub fn is_range_selector(selector: &str) -> bool {
+    selector.split(SELECTOR_SEPARATOR).any(NumRange::is_range)
+}
+
+/// Parse range selector into a [`StageRange`]. Missing numbers are treated as
+/// wildcards, so `"ex"` and `"ex *"` both select every Extra stage.
+pub fn parse_stage_range(selector: &str) -> Result<StageRange, StageTypeParseError> {
+    let mut iter = selector.split(SELECTOR_SEPARATOR);
+    let compare = iter.next().expect("Shouldn't panic on first next.");
+    let variant = get_variant_from_code(compare).ok_or(StageTypeParseError::UnknownMatcher)?;
+
+    if variant.has_single_stage() {
+        return Ok(StageRange {
+            variant,
+            map: NumRange::Between(0, 0),
+            stage: NumRange::Between(0, 0),
+        });
+    }
+
+    let is_eoc = variant == T::MainChapters && compare.eq_ignore_ascii_case("eoc");
+    if variant.has_single_map() || is_eoc {
+        // same as `parse_stage_selector`, last number is always the stage
+        let stage = NumRange::parse(iter.next_back())?;
+        return Ok(StageRange {
+            variant,
+            map: NumRange::Between(0, 0),
+            stage,
+        });
+    }
+
+    let map = NumRange::parse(iter.next())?;
+    let map = match (variant, compare.to_lowercase().as_str()) {
+        // see `parse_map_selector`
+        (T::MainChapters, "itf" | "w") => match map {
+            NumRange::All => NumRange::Between(3, 5),
+            map => map.offset(2),
+        },
+        (T::MainChapters, "cotc" | "space") => match map {
+            NumRange::All => NumRange::Between(6, 8),
+            map => map.offset(5),
+        },
+        _ => map,
+    };
+    let stage = NumRange::parse(iter.next())?;
+
+    Ok(StageRange {
+        variant,
+        map,
+        stage,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use StageTypeParseError as E;
+
+    #[test]
+    fn detect_range() {
+        assert!(is_range_selector("sol 0 *"));
+        assert!(is_range_selector("event 200 0-5"));
+        assert!(is_range_selector("ex *"));
+        assert!(!is_range_selector("sol 0 0"));
+        assert!(!is_range_selector("s01001-999"));
+        assert!(!is_range_selector(
+            "*https://battlecats-db.com/stage/s01382-03.html"
+        ));
+        assert!(!is_range_selector("stageRN013_05.csv"));
+    }
+
+    #[test]
+    fn parse_ranges() {
+        assert_eq!(
+            parse_stage_range("sol 0 *").unwrap(),
+            StageRange {
+                variant: T::SoL,
+                map: NumRange::Between(0, 0),
+                stage: NumRange::All
+            }
+        );
+        assert_eq!(
+            parse_stage_range("event 200 0-5").unwrap(),
+            StageRange {
+                variant: T::Event,
+                map: NumRange::Between(200, 200),
+                stage: NumRange::Between(0, 5)
+            }
+        );
+        assert_eq!(
+            parse_stage_range("ex *").unwrap(),
+            StageRange {
+                variant: T::Extra,
+                map: NumRange::All,
+                stage: NumRange::All
+            }
+        );
+    }
+
+    #[test]
+    fn parse_main() {
+        assert_eq!(
+            parse_stage_range("itf *").unwrap(),
+            StageRange {
+                variant: T::MainChapters,
+                map: NumRange::Between(3, 5),
+                stage: NumRange::All
+            }
+        );
+        assert_eq!(
+            parse_stage_range("cotc 1 0-47").unwrap(),
+            StageRange {
+                variant: T::MainChapters,
+                map: NumRange::Between(6, 6),
+                stage: NumRange::Between(0, 47)
+            }
+        );
+        assert_eq!(
+            parse_stage_range("eoc 0-9").unwrap(),
+            StageRange {
+                variant: T::MainChapters,
+                map: NumRange::Between(0, 0),
+                stage: NumRange::Between(0, 9)
+            }
+        );
+    }
+
+    #[test]
+    fn parse_single() {
+        assert_eq!(
+            parse_stage_range("aku *").unwrap(),
+            StageRange {
+                variant: T::AkuRealms,
+                map: NumRange::Between(0, 0),
+                stage: NumRange::All
+            }
+        );
+        assert_eq!(
+            parse_stage_range("filibuster *").unwrap(),
+            StageRange {
+                variant: T::Filibuster,
+                map: NumRange::Between(0, 0),
+                stage: NumRange::Between(0, 0)
+            }
+        );
+    }
+
+    #[test]
+    fn contains() {
+        let range = parse_stage_range("event 200 0-5").unwrap();
+        assert!(range.contains(&StageID::from_components(T::Event, 200, 0)));
+        assert!(range.contains(&StageID::from_components(T::Event, 200, 5)));
+        assert!(!range.contains(&StageID::from_components(T::Event, 200, 6)));
+        assert!(!range.contains(&StageID::from_components(T::Event, 201, 0)));
+        assert!(!range.contains(&StageID::from_components(T::SoL, 200, 0)));
+    }
+
+    #[test]
+    fn range_errors() {
+        assert_eq!(parse_stage_range("unknown *"), Err(E::UnknownMatcher));
+        assert_eq!(parse_stage_range("sol 5-0 *"), Err(E::InvalidNumber));
+        assert_eq!(parse_stage_range("sol a-b *"), Err(E::InvalidNumber));
+    }
+}
@@ -1,5 +1,6 @@
 //! The variant (e.g. SoL, main chapters etc.) of the stage.
 
+use serde::Serialize;
 use strum::{EnumIter, FromRepr};
 
 const _: () = assert!(std::mem::size_of::<StageVariantID>() == std::mem::size_of::<VariantSize>());
@@ -9,7 +10,7 @@ pub type VariantSize = u32;
 
 #[allow(missing_docs)]
 #[repr(u32)]
-#[derive(Debug, Copy, Clone, FromRepr, EnumIter, PartialEq)]
+#[derive(Debug, Copy, Clone, FromRepr, EnumIter, PartialEq, Serialize)]
 /// The variant (e.g. SoL, main chapters etc.) of the stage.
 pub enum StageVariantID {
     // TrueFormUnlocks = 28,
@@ -3,6 +3,8 @@
 pub mod cat;
 pub mod csv;
 pub mod enemy;
+pub mod gacha;
+pub mod item;
 pub mod map;
 pub mod meta;
 pub mod stage;
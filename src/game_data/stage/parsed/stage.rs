@@ -10,6 +10,7 @@ use crate::game_data::{
     },
     meta::stage::{stage_id::StageID, stage_types::parse::parse_stage::parse_general_stage_id},
     stage::raw::{
+        fixed_formation::FixedUnit,
         stage_data::{FromSelectorError, StageData},
         stage_option::{
             StageOptionCSV,
@@ -18,9 +19,10 @@ use crate::game_data::{
     },
     version::Version,
 };
+use serde::Serialize;
 use std::num::NonZeroU32;
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 /// Rewards for the stage.
 pub struct StageRewards {
     /// Modifier for the treasure drop.
@@ -31,7 +33,7 @@ pub struct StageRewards {
     pub score_rewards: Vec<ScoreRewardsCSV>,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Possible continuation stages.
 pub struct ContinueStages {
     /// Chance of continuing.
@@ -51,7 +53,7 @@ impl ContinueStages {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 /// Crown difficulty data.
 pub struct CrownData {
     /// Max crown difficulty.
@@ -87,7 +89,7 @@ impl From<&MapOptionCSV> for CrownData {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 /// Crowns that restriction applies to.
 pub enum RestrictionCrowns {
     /// All crown difficulties.
@@ -104,7 +106,7 @@ impl From<i8> for RestrictionCrowns {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 /// Stages that restriction applies to.
 pub enum RestrictionStages {
     /// Applies to all stages.
@@ -124,7 +126,7 @@ impl From<i32> for RestrictionStages {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 /// Stage's restriction. Multiple fields can be active at once.
 pub struct Restriction {
     /// Which stages the restriction applies to.
@@ -172,7 +174,7 @@ impl Restriction {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Full Version-agnostic owned stage struct.
 pub struct Stage {
     /// Unique identifier for stage.
@@ -228,6 +230,8 @@ pub struct Stage {
     pub ex_invasion: Option<u32>,
     /// Stage's restrictions.
     pub restrictions: Option<Vec<Restriction>>,
+    /// Units the stage must be played with.
+    pub fixed_formation: Option<Vec<FixedUnit>>,
     /// Stage's rules.
     pub rules: Option<SpecialRule>,
     /// Stage's score bonuses.
@@ -240,6 +244,9 @@ impl From<StageData<'_>> for Stage {
         let ex_invasion = data.get_ex_option_data();
         let rules = data.get_special_rules_data().cloned();
         let bonuses = data.get_score_bonus_data().cloned();
+        let fixed_formation = data
+            .get_fixed_formation_data()
+            .map(|formation| formation.units.clone());
         log::debug!("Rules: {rules:?}");
         log::debug!("Bonuses: {bonuses:?}");
         // debug to reveal how the stage's rule works, useful for updates which
@@ -353,6 +360,7 @@ impl From<StageData<'_>> for Stage {
 
             ex_invasion,
             restrictions,
+            fixed_formation,
             rules,
             bonuses,
         }
@@ -2,11 +2,12 @@
 
 use crate::game_data::stage::raw::stage_data::csv_types::StageEnemyCSV;
 use either::Either::{self, Left, Right};
+use serde::Serialize;
 use std::num::NonZeroU32;
 use strum::FromRepr;
 
 #[repr(u32)]
-#[derive(Debug, PartialEq, FromRepr)]
+#[derive(Debug, PartialEq, FromRepr, Serialize)]
 /// Type of boss.
 pub enum BossType {
     /// Isn't a boss.
@@ -22,7 +23,7 @@ impl From<u32> for BossType {
     }
 }
 
-#[derive(Debug, PartialEq)]
+#[derive(Debug, PartialEq, Serialize)]
 /// Amount of the enemy that spawns.
 pub enum EnemyAmount {
     /// Infinite.
@@ -54,7 +55,7 @@ pub const MS_SIGN: u32 = 21;
 
 /// Enemy magnification.
 pub type Magnification = Either<u32, (u32, u32)>;
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Representation of an enemy in a stage.
 pub struct StageEnemy {
     /// Wiki id (Doge is 0).
@@ -0,0 +1,189 @@
+//! Module that deals with the `fixed_formation` file.
+//!
+//! After the header line, each line is a map's mapid, the stage id (-1 for
+//! every stage in the map) and then a `unit id, form, level` triple for each
+//! slot in the lineup. A unit id of -1 marks an empty slot.
+
+use crate::game_data::{
+    meta::stage::{map_id::MapID, stage_id::StageID},
+    version::{
+        Version,
+        version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+    },
+};
+use serde::Serialize;
+use std::{collections::HashMap, error::Error, fs::File, io::Read};
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Unit in a fixed lineup.
+pub struct FixedUnit {
+    /// Unit's id.
+    pub id: u32,
+    /// 0-based form of the unit.
+    pub form: u8,
+    /// Unit's level.
+    pub level: u16,
+}
+
+#[derive(Debug)]
+/// Data stored in the fixed formation CSV.
+pub struct FixedFormationCSV {
+    /// Stage's map's mapid.
+    pub mapid: u32,
+    /// If is -1 then applies to all stages in map. Otherwise only applies to
+    /// the stage in the map with that id.
+    pub stage_id: i32,
+    /// Units in the lineup.
+    pub units: Vec<FixedUnit>,
+}
+impl FixedFormationCSV {
+    /// Parse a single line of the file.
+    fn from_record(record: &[i32]) -> Result<Self, Box<dyn Error>> {
+        let [mapid, stage_id, slots @ ..] = record else {
+            return Err(format!("Fixed formation line is too short: {record:?}").into());
+        };
+        if slots.len() % 3 != 0 {
+            return Err(format!("Fixed formation line has incomplete slots: {record:?}").into());
+        }
+
+        let units = slots
+            .chunks_exact(3)
+            .filter(|slot| slot[0] >= 0)
+            .map(|slot| {
+                Ok(FixedUnit {
+                    id: slot[0].try_into()?,
+                    form: slot[1].try_into()?,
+                    level: slot[2].try_into()?,
+                })
+            })
+            .collect::<Result<_, Box<dyn Error>>>()?;
+
+        Ok(Self {
+            mapid: (*mapid).try_into()?,
+            stage_id: *stage_id,
+            units,
+        })
+    }
+}
+
+fn get_fixed_formation(
+    reader: impl Read,
+) -> Result<HashMap<u32, Vec<FixedFormationCSV>>, Box<dyn Error>> {
+    let mut rdr = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .comment(Some(b'/'))
+        .from_reader(reader);
+
+    let records = rdr.records().skip(1);
+
+    let mut map: HashMap<u32, Vec<FixedFormationCSV>> = HashMap::new();
+    for record in records {
+        let record = record.map_err(Box::new)?;
+        let values = record
+            .iter()
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .map(str::parse)
+            .collect::<Result<Vec<i32>, _>>()?;
+
+        let result = FixedFormationCSV::from_record(&values)?;
+        map.entry(result.mapid).or_default().push(result);
+    }
+
+    Ok(map)
+}
+
+#[derive(Debug, Default)]
+/// Container for fixed formation data.
+pub struct FixedFormations {
+    map: HashMap<u32, Vec<FixedFormationCSV>>,
+}
+impl FixedFormations {
+    /// Get the data for the map that `map_id` corresponds to.
+    pub fn get_map(&self, map_id: &MapID) -> Option<&Vec<FixedFormationCSV>> {
+        self.map.get(&map_id.mapid())
+    }
+
+    /// Get the stage's fixed lineup. A lineup for that specific stage takes
+    /// priority over one for the entire map.
+    pub fn get_stage(&self, stage_id: &StageID) -> Option<&FixedFormationCSV> {
+        let map = self.get_map(stage_id.map())?;
+        map.iter()
+            .find(|formation| formation.stage_id == stage_id.num() as i32)
+            .or_else(|| map.iter().find(|formation| formation.stage_id == -1))
+    }
+}
+impl CacheableVersionData for FixedFormations {
+    fn create(version: &Version) -> CvdResult<Self> {
+        // file doesn't exist in older versions
+        let file = File::open(version.location().join("DataLocal/fixed_formation.csv"))
+            .map_err(CvdCreateError::default_from_err)?;
+        Ok(Self {
+            map: get_fixed_formation(file).map_err(CvdCreateError::throw)?,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn parse_line() {
+        let line =
+            FixedFormationCSV::from_record(&[1234, -1, 0, 2, 30, 13, 0, 50, -1, 0, 0]).unwrap();
+        assert_eq!(line.mapid, 1234);
+        assert_eq!(line.stage_id, -1);
+        assert_eq!(
+            line.units,
+            [
+                FixedUnit {
+                    id: 0,
+                    form: 2,
+                    level: 30
+                },
+                FixedUnit {
+                    id: 13,
+                    form: 0,
+                    level: 50
+                }
+            ]
+        );
+    }
+
+    #[test]
+    fn incomplete_line() {
+        assert!(FixedFormationCSV::from_record(&[1234]).is_err());
+        assert!(FixedFormationCSV::from_record(&[1234, 0, 5, 1]).is_err());
+    }
+
+    #[test]
+    fn parse_file() {
+        let file = "mapid,stage,id1,form1,lv1,id2,form2,lv2\n\
+            // comment\n\
+            1234,-1,0,2,30,13,0,50\n\
+            1234,3,-1,0,0,-1,0,0,\n";
+        let map = get_fixed_formation(file.as_bytes()).unwrap();
+        let formations = &map[&1234];
+        assert_eq!(formations.len(), 2);
+        assert_eq!(formations[0].units.len(), 2);
+        assert_eq!(formations[1].stage_id, 3);
+        assert!(formations[1].units.is_empty());
+
+        assert!(get_fixed_formation("header\n1234,-1,a,0,0\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn real_file() {
+        let version = TEST_CONFIG.version.current_version();
+        let file = File::open(version.location().join("DataLocal/fixed_formation.csv")).unwrap();
+        let map = get_fixed_formation(file).unwrap();
+        assert!(!map.is_empty());
+        for formation in map.values().flatten() {
+            assert!(formation.stage_id >= -1);
+            assert!(formation.units.iter().all(|unit| unit.form < 4));
+        }
+    }
+}
@@ -1,4 +1,6 @@
 //! Get raw data about a stage.
 
+pub mod fixed_formation;
 pub mod stage_data;
+pub mod stage_index;
 pub mod stage_option;
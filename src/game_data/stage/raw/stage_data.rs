@@ -1,5 +1,5 @@
 //! Module that deals with getting information about stages.
-use super::stage_option::StageOptionCSV;
+use super::{fixed_formation::FixedFormationCSV, stage_option::StageOptionCSV};
 use crate::game_data::{
     csv::{CSVParseError, CSVParseErrorKind, CSVParseErrorLine, FullCSVError},
     map::{
@@ -22,7 +22,9 @@ use std::{fs::File, io::BufReader, path::PathBuf};
 /// Types to deserialise csv files.
 pub mod csv_types {
     // TODO split this up
-    #[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
+    #[derive(
+        Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, serde::Deserialize, serde::Serialize,
+    )]
     /// Data stored in the header of the csv file (minus most Main Chapters).
     pub struct HeaderCSV {
         /// ID of base used.
@@ -39,7 +41,9 @@ pub mod csv_types {
         pub cont_stage_id_max: u32,
     }
 
-    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
+    #[derive(
+        Debug, PartialEq, Eq, PartialOrd, Ord, Clone, serde::Deserialize, serde::Serialize,
+    )]
     /// Data stored in line 2 of the csv file (line 1 for most Main Chapter
     /// stages).
     pub struct Line2CSV {
@@ -62,7 +66,9 @@ pub mod csv_types {
         pub(super) _unknown_3: Option<u32>,
     }
 
-    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, serde::Deserialize)]
+    #[derive(
+        Debug, PartialEq, Eq, PartialOrd, Ord, Clone, serde::Deserialize, serde::Serialize,
+    )]
     /// CSV data for enemies. See [Stage Structure
     /// Page/Battlegrounds](https://battlecats.miraheze.org/wiki/The_Battle_Cats_Wiki:Stage_Structure_Page/Battlegrounds)
     /// for more complete documentation.
@@ -105,7 +111,9 @@ pub mod csv_types {
     }
 
     /// Raw data from the stage csv file.
-    #[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+    #[derive(
+        Debug, PartialEq, Eq, PartialOrd, Ord, Clone, serde::Deserialize, serde::Serialize,
+    )]
     pub struct RawCSVData {
         /// Header row.
         pub header: HeaderCSV,
@@ -177,6 +185,19 @@ impl<'a> StageData<'_> {
         })
     }
 
+    /// Create stage data object from already-parsed csv data.
+    pub fn from_raw(
+        id: StageID,
+        stage_csv_data: RawCSVData,
+        version: &'a Version,
+    ) -> StageData<'a> {
+        StageData {
+            id,
+            stage_csv_data,
+            version,
+        }
+    }
+
     /// Read a stage's csv file and obtain the data from it.
     pub fn read_stage_csv<R: std::io::Read>(reader: R) -> Result<RawCSVData, CSVParseErrorLine> {
         type E = CSVParseErrorKind;
@@ -230,6 +251,11 @@ impl<'a> StageData<'_> {
         GameMapData::stage_stage_option_data(&self.id, self.version)
     }
 
+    /// Get fixed_formation data if it exists.
+    pub fn get_fixed_formation_data(&self) -> Option<&FixedFormationCSV> {
+        GameMapData::get_fixed_formation_data(&self.id, self.version)
+    }
+
     /// Get Map_option data if it exists.
     pub fn get_ex_option_data(&self) -> Option<u32> {
         GameMapData::get_ex_option_data(self.id.map(), self.version)
@@ -0,0 +1,248 @@
+//! Persistent on-disk index of every stage file in a version.
+//!
+//! Parsing every `stage*.csv` file takes a long time, so the parsed data is
+//! stored in a `.rust-wiki-cache/` directory inside the version's data
+//! directory and only files whose modification times have changed since the
+//! index was last written get re-parsed.
+
+use super::stage_data::{FromSelectorError, StageData, csv_types::RawCSVData};
+use crate::game_data::{
+    meta::stage::{stage_id::StageID, stage_types::parse::parse_stage::parse_stage_file},
+    stage::stage_util::get_stage_files,
+    version::{
+        Version,
+        version_data::{CacheableVersionData, CvdCreateError, CvdResult},
+    },
+};
+use serde::{Deserialize, Serialize};
+use std::{
+    collections::HashMap,
+    fs::{self, File},
+    io::{BufReader, BufWriter},
+    path::PathBuf,
+    time::UNIX_EPOCH,
+};
+
+/// Bump this whenever the format of [`RawCSVData`] or [`IndexFile`] changes.
+const INDEX_FORMAT: u32 = 2;
+/// Directory inside the version's location that the index is stored in.
+const CACHE_DIR: &str = ".rust-wiki-cache";
+
+#[derive(Debug, Serialize, Deserialize)]
+/// Single stage file in the index.
+struct IndexedFile {
+    /// File name, e.g. `stageRN000_00.csv`.
+    name: String,
+    /// Modification time of the file in nanoseconds since the Unix epoch.
+    mtime: u128,
+    /// Parsed data.
+    data: RawCSVData,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+/// Format of the index on disk.
+struct IndexFile {
+    /// Should be [`INDEX_FORMAT`].
+    format: u32,
+    /// Canonicalised location of the version that was indexed.
+    location: PathBuf,
+    /// Game version number, if it is known.
+    number: Option<String>,
+    /// All stage files.
+    files: Vec<IndexedFile>,
+}
+
+/// Location of the index file for `version`.
+fn index_path(version: &Version) -> PathBuf {
+    version
+        .location()
+        .join(CACHE_DIR)
+        .join(format!("stages_{}.json", version.language()))
+}
+
+/// Canonicalised location of `version`, so that the same directory always
+/// gives the same path.
+fn canonical_location(version: &Version) -> PathBuf {
+    fs::canonicalize(version.location()).unwrap_or_else(|_| version.location().to_path_buf())
+}
+
+/// Modification time of `DataLocal/{file_name}`.
+fn get_mtime(file_name: &str, version: &Version) -> Option<u128> {
+    let path = version.get_file_path("DataLocal").join(file_name);
+    let modified = fs::metadata(path).ok()?.modified().ok()?;
+    Some(modified.duration_since(UNIX_EPOCH).ok()?.as_nanos())
+}
+
+/// Read the index from disk. Returns [`None`] if the index doesn't exist or is
+/// invalid for `version`.
+fn read_index(version: &Version) -> Option<IndexFile> {
+    let path = index_path(version);
+    let file = File::open(&path).ok()?;
+    let index: IndexFile = match serde_json::from_reader(BufReader::new(file)) {
+        Ok(index) => index,
+        Err(e) => {
+            log::info!("Couldn't read stage index at {path:?}, rebuilding: {e}");
+            return None;
+        }
+    };
+
+    if index.format != INDEX_FORMAT
+        || index.location != canonical_location(version)
+        || index.number.as_deref() != version.try_number()
+    {
+        log::info!("Stage index at {path:?} is outdated, rebuilding.");
+        return None;
+    }
+
+    Some(index)
+}
+
+/// Write the index to disk. Failing to write is not an error.
+fn write_index(index: &IndexFile, version: &Version) {
+    let path = index_path(version);
+    let write = || -> Result<(), Box<dyn std::error::Error>> {
+        fs::create_dir_all(path.parent().expect("path is always inside `CACHE_DIR`"))?;
+        let file = File::create(&path)?;
+        serde_json::to_writer(BufWriter::new(file), index)?;
+        Ok(())
+    };
+
+    if let Err(e) = write() {
+        log::warn!("Couldn't write stage index to {path:?}: {e}");
+    }
+}
+
+/// Get the up-to-date index, re-parsing any stage files that have changed.
+fn get_index(version: &Version) -> Result<IndexFile, FromSelectorError> {
+    let mut old_files = read_index(version)
+        .map(|index| {
+            index
+                .files
+                .into_iter()
+                .map(|file| (file.name.clone(), file))
+                .collect::<HashMap<_, _>>()
+        })
+        .unwrap_or_default();
+    let old_len = old_files.len();
+
+    let mut files = vec![];
+    let mut reused = 0;
+    for name in get_stage_files(version) {
+        let mtime = get_mtime(&name, version).unwrap_or_default();
+        if let Some(file) = old_files.remove(&name)
+            && file.mtime == mtime
+        {
+            files.push(file);
+            reused += 1;
+            continue;
+        }
+
+        let data = StageData::from_file_name(&name, version)?.stage_csv_data;
+        files.push(IndexedFile { name, mtime, data });
+    }
+
+    let index = IndexFile {
+        format: INDEX_FORMAT,
+        location: canonical_location(version),
+        number: version.try_number().map(String::from),
+        files,
+    };
+
+    if reused != index.files.len() || reused != old_len {
+        write_index(&index, version);
+    }
+
+    Ok(index)
+}
+
+#[derive(Debug, Default)]
+/// Parsed data of every stage file in the version.
+pub struct StageIndex {
+    stages: Vec<(StageID, RawCSVData)>,
+}
+impl StageIndex {
+    /// Iterate over every stage in the index.
+    pub fn iter(&self) -> impl Iterator<Item = &(StageID, RawCSVData)> {
+        self.stages.iter()
+    }
+
+    /// Get an iterator over all stages in the version as [`StageData`].
+    pub fn stage_data<'a>(&'a self, version: &'a Version) -> impl Iterator<Item = StageData<'a>> {
+        self.iter()
+            .map(|(id, data)| StageData::from_raw(id.clone(), data.clone(), version))
+    }
+
+    /// Amount of stages in the index.
+    pub fn len(&self) -> usize {
+        self.stages.len()
+    }
+
+    /// Is the index empty.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+    }
+}
+impl CacheableVersionData for StageIndex {
+    fn create(version: &Version) -> CvdResult<Self> {
+        let index = get_index(version).map_err(CvdCreateError::throw_from_err)?;
+
+        let stages = index
+            .files
+            .into_iter()
+            .map(|file| {
+                let id = parse_stage_file(&file.name).expect("file name was parsed when indexed");
+                (id, file.data)
+            })
+            .collect();
+
+        Ok(Self { stages })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn round_trip() {
+        const STAGE: &str = "3,0,0,0,0,0\n\
+            4200,60000,1,300,3,10,0,0,0,\n\
+            2,0,0,30,90,100,0,9,0,100,\n\
+            0,0,0,0,0,0,0,0,0,0,";
+        let data = StageData::read_stage_csv(STAGE.as_bytes()).unwrap();
+
+        let index = IndexFile {
+            format: INDEX_FORMAT,
+            location: PathBuf::from("/data/en"),
+            number: Some("14.0".into()),
+            files: vec![IndexedFile {
+                name: "stageRN000_00.csv".into(),
+                mtime: 1,
+                data,
+            }],
+        };
+        let json = serde_json::to_string(&index).unwrap();
+        let read: IndexFile = serde_json::from_str(&json).unwrap();
+
+        assert_eq!(read.location, index.location);
+        assert_eq!(read.files[0].data, index.files[0].data);
+        assert_eq!(read.files[0].data.enemies.len(), 1);
+    }
+
+    #[test]
+    fn separate_trees() {
+        use crate::game_data::version::lang::VersionLanguage;
+        let a = Version::new("/data/a/Version 14.0", VersionLanguage::EN, None);
+        let b = Version::new("/data/b/Version 14.0", VersionLanguage::EN, None);
+        assert_ne!(index_path(&a), index_path(&b));
+        assert!(index_path(&a).starts_with("/data/a/Version 14.0"));
+    }
+
+    #[test]
+    fn index_matches_files() {
+        let version = TEST_CONFIG.version.current_version();
+        let index = version.get_cached_file::<StageIndex>();
+        assert_eq!(index.len(), get_stage_files(version).count());
+    }
+}
@@ -16,6 +16,7 @@ pub mod charagroups {
         Version,
         version_data::{CacheableVersionData, CvdCreateError, CvdResult},
     };
+    use serde::Serialize;
     use std::{error::Error, path::Path};
     use strum::FromRepr;
 
@@ -31,7 +32,7 @@ pub mod charagroups {
     }
 
     #[repr(u32)]
-    #[derive(Debug, PartialEq, Clone, FromRepr)]
+    #[derive(Debug, PartialEq, Clone, FromRepr, Serialize)]
     /// Type of the Charagroup.
     pub enum CharaGroupType {
         /// Can only use select cats.
@@ -49,7 +50,7 @@ pub mod charagroups {
         }
     }
 
-    #[derive(Debug, PartialEq, Clone)]
+    #[derive(Debug, PartialEq, Clone, Serialize)]
     /// Data about a [`CharaGroup`].
     pub struct CharaGroup {
         /// ID of charagroup.
@@ -1,7 +1,13 @@
 //! Utility functions for dealing with stages.
 
 use super::super::version::Version;
-use crate::regex_handler::static_regex;
+use crate::{
+    game_data::meta::stage::{
+        stage_id::StageID,
+        stage_types::parse::{parse_range::StageRange, parse_stage::parse_stage_file},
+    },
+    regex_handler::static_regex,
+};
 
 /// Get a list of all stage data files in the game.
 pub fn get_stage_files(version: &Version) -> impl Iterator<Item = String> {
@@ -21,3 +27,14 @@ pub fn get_stage_files(version: &Version) -> impl Iterator<Item = String> {
         }
     })
 }
+
+/// Get the ids of every stage in the game that is part of `range`, sorted by
+/// id.
+pub fn get_range_stages(range: &StageRange, version: &Version) -> Vec<StageID> {
+    let mut ids = get_stage_files(version)
+        .filter_map(|file| parse_stage_file(&file).ok())
+        .filter(|id| range.contains(id))
+        .collect::<Vec<_>>();
+    ids.sort_by_key(|id| (id.variant().num(), id.map().num(), id.num()));
+    ids
+}
@@ -58,10 +58,14 @@ impl Version {
 
     /// Get version's number.
     pub fn number(&self) -> &str {
-        let loc = self.location.to_str().unwrap();
+        self.try_number().unwrap()
+    }
+
+    /// Get version's number, or [`None`] if the location doesn't contain it.
+    pub fn try_number(&self) -> Option<&str> {
+        let loc = self.location.to_str()?;
         loc.split_whitespace()
             .find(|&part| part.chars().all(|c| c.is_ascii_digit() || c == '.'))
-            .unwrap()
     }
 
     /// Get version's number, in the same format as unitbuy.
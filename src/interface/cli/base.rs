@@ -3,22 +3,32 @@
 use super::cli_util::ConfigMerge;
 use crate::{interface::config::Config, logger::set_log_level};
 use clap::{
-    Args,
+    Args, ValueEnum,
     builder::{PossibleValuesParser, TypedValueParser},
 };
 use log::Level;
 
 const POSSIBLE_LOG_LEVELS: [&str; 5] = ["error", "warn", "info", "debug", "trace"];
 
+#[derive(Debug, Default, Clone, Copy, ValueEnum, PartialEq)]
+/// Format of a command's output.
+pub enum OutputFormat {
+    #[default]
+    /// Wikitext.
+    Wikitext,
+    /// Parsed game data as JSON.
+    Json,
+}
+
 #[derive(Debug, Default, Args, PartialEq)]
 /// Options that can apply to every submodule.
-pub struct BaseOptions {
+pub struct LogOptions {
     #[arg(value_parser = PossibleValuesParser::new(POSSIBLE_LOG_LEVELS).map(|s| s.parse::<Level>().unwrap()))]
     #[arg(ignore_case = true, short)]
     /// Log level.
     pub log: Option<Level>,
 }
-impl ConfigMerge for BaseOptions {
+impl ConfigMerge for LogOptions {
     fn merge(&self, config: &mut Config) {
         if let Some(log) = self.log {
             config.log_level = log;
@@ -29,6 +39,29 @@ impl ConfigMerge for BaseOptions {
     }
 }
 
+#[derive(Debug, Default, Args, PartialEq)]
+/// Options that apply to every submodule that can output parsed data.
+pub struct BaseOptions {
+    #[command(flatten)]
+    /// Logging options.
+    pub logging: LogOptions,
+
+    #[arg(long, value_enum, default_value_t)]
+    /// Output format.
+    pub format: OutputFormat,
+}
+impl BaseOptions {
+    /// Should the command output JSON instead of wikitext.
+    pub fn is_json(&self) -> bool {
+        self.format == OutputFormat::Json
+    }
+}
+impl ConfigMerge for BaseOptions {
+    fn merge(&self, config: &mut Config) {
+        self.logging.merge(config);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -41,4 +74,32 @@ mod tests {
             assert_eq!(comp, run);
         }
     }
+
+    #[test]
+    fn json_format() {
+        use crate::interface::{
+            cli::commands::{Cli, Command},
+            scripts::map_info::map_cli::MapInfoOptions,
+        };
+        use clap::Parser;
+
+        const ARGS: [&str; 5] = ["run_program", "map", "l 0", "--format", "json"];
+        let cli = Cli::parse_from(ARGS.iter());
+        let Command::MapInfo(MapInfoOptions { base, .. }) = cli.command else {
+            panic!("Command should be map-info.");
+        };
+        assert_eq!(base.format, OutputFormat::Json);
+        assert!(base.is_json());
+    }
+
+    #[test]
+    fn no_json() {
+        use crate::interface::cli::commands::Cli;
+        use clap::Parser;
+
+        const CHECK_ARGS: [&str; 6] = ["run_program", "check-page", "cat", "0", "--format", "json"];
+        assert!(Cli::try_parse_from(CHECK_ARGS.iter()).is_err());
+        const READ_ARGS: [&str; 4] = ["run_program", "read-wiki", "--format", "json"];
+        assert!(Cli::try_parse_from(READ_ARGS.iter()).is_err());
+    }
 }
@@ -1,7 +1,11 @@
 //! Utility functions and traits for the cli.
 
 use crate::interface::config::Config;
-use std::io::{self, Write};
+use serde::Serialize;
+use std::{
+    fmt::Display,
+    io::{self, Write},
+};
 
 /// Syntax sugar for a function that works like Python's `input`.
 pub fn input(prompt: &str) -> String {
@@ -10,6 +14,47 @@ pub fn input(prompt: &str) -> String {
     io::stdin().lines().next().unwrap().unwrap()
 }
 
+/// Print `value` to stdout as pretty-printed JSON.
+pub fn print_json<T: Serialize + ?Sized>(value: &T) {
+    let json = serde_json::to_string_pretty(value).expect("data should always be serialisable");
+    println!("{json}");
+}
+
+/// Characters that can't be used in file names on at least one platform.
+const INVALID_FILE_CHARS: [char; 9] = ['/', '\\', ':', '*', '?', '"', '<', '>', '|'];
+
+/// Get a file name from `name` that is valid on all platforms.
+pub fn file_name(name: &str, extension: &str) -> String {
+    let name = name.replace(INVALID_FILE_CHARS, "_");
+    format!("{name}.{extension}")
+}
+
+/// Get the file name of every item in `items`, which is a list of `(name,
+/// id)`. Items whose file names would collide with a different item's
+/// (ignoring case) get their id added, e.g. `Doge (0).txt`.
+pub fn file_names<T: PartialEq + Display>(items: &[(&str, T)], extension: &str) -> Vec<String> {
+    let names = items
+        .iter()
+        .map(|(name, _)| file_name(name, extension).to_lowercase())
+        .collect::<Vec<_>>();
+
+    items
+        .iter()
+        .zip(&names)
+        .map(|((name, id), lower)| {
+            let collides = items
+                .iter()
+                .zip(&names)
+                .any(|((_, other_id), other)| other_id != id && other == lower);
+            if collides {
+                file_name(&format!("{name} ({id})"), extension)
+            } else {
+                file_name(name, extension)
+            }
+        })
+        .collect()
+}
+
 /// Overwrite values of a [`Config`] object.
 pub trait ConfigMerge {
     /// Overwrite values of `config` with equivalent values from `&self`.
@@ -32,3 +77,41 @@ pub trait CliCommand: ConfigMerge + CommandExec {
 }
 
 impl<T> CliCommand for T where T: ConfigMerge + CommandExec {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn invalid_file_chars() {
+        assert_eq!(
+            file_name("Crazed Cat (Deadly)", "txt"),
+            "Crazed Cat (Deadly).txt"
+        );
+        assert_eq!(file_name("Who? What/Where", "txt"), "Who_ What_Where.txt");
+        assert_eq!(file_name("A: B", "json"), "A_ B.json");
+    }
+
+    #[test]
+    fn colliding_file_names() {
+        let enemies = [("Doge", 0), ("Snache", 1), ("doge", 2), ("Doge", 0)];
+        assert_eq!(
+            file_names(&enemies, "txt"),
+            ["Doge (0).txt", "Snache.txt", "doge (2).txt", "Doge (0).txt"]
+        );
+
+        let pages = [
+            ("Stage", "000-000-000".to_string()),
+            ("stage", "000-000-001".to_string()),
+            ("Other", "000-000-002".to_string()),
+        ];
+        assert_eq!(
+            file_names(&pages, "txt"),
+            [
+                "Stage (000-000-000).txt",
+                "stage (000-000-001).txt",
+                "Other.txt"
+            ]
+        );
+    }
+}
@@ -4,9 +4,16 @@ use super::cli_util::CliCommand;
 use crate::interface::{
     config::Config,
     scripts::{
-        cat_info::cat_cli::CatInfoOptions, encounters::encounters_cli::EncountersOptions,
-        gauntlet::gauntlet_cli::GauntletOptions, map_info::map_cli::MapInfoOptions,
-        read_wiki::wiki_cli::ReadWikiOptions, stage_info::stage_cli::StageInfoOptions,
+        cat_info::cat_cli::CatInfoOptions,
+        diff_versions::diff_cli::DiffVersionsOptions,
+        encounters::encounters_cli::EncountersOptions,
+        enemy_info::enemy_cli::EnemyInfoOptions,
+        gacha::gacha_cli::GachaOptions,
+        gauntlet::gauntlet_cli::GauntletOptions,
+        map_info::map_cli::MapInfoOptions,
+        orb_info::orb_cli::OrbInfoOptions,
+        read_wiki::{check_cli::CheckPageOptions, wiki_cli::ReadWikiOptions},
+        stage_info::stage_cli::StageInfoOptions,
     },
 };
 use clap::{Parser, Subcommand};
@@ -33,6 +40,14 @@ pub enum Command {
     /// Get information about a cat.
     CatInfo(CatInfoOptions),
 
+    #[command(visible_aliases(["enemy"]))]
+    /// Get information about an enemy.
+    EnemyInfo(EnemyInfoOptions),
+
+    #[command(visible_aliases(["orb"]))]
+    /// Get information about every talent orb.
+    OrbInfo(OrbInfoOptions),
+
     /// Get most boilerplate for a gauntlet map.
     ///
     /// See <https://battlecats.miraheze.org/wiki/?diff=207709> for a list of
@@ -40,6 +55,16 @@ pub enum Command {
     /// (gauntlet.py)[<https://battlecats.miraheze.org/wiki/User:TheWWRNerdGuy/scripts#gauntlet.py>]
     /// can fix most problems.
     Gauntlet(GauntletOptions),
+
+    /// Get gacha banners from the event data.
+    Gacha(GachaOptions),
+
+    #[command(visible_aliases(["diff"]))]
+    /// Find what changed between two versions of the game.
+    DiffVersions(DiffVersionsOptions),
+
+    /// Regenerate a page and compare it to the version on the wiki.
+    CheckPage(CheckPageOptions),
 }
 
 #[derive(Parser, Debug, PartialEq)]
@@ -60,7 +85,12 @@ impl Cli {
             Command::ReadWiki(options) => options.run(config),
             Command::MapInfo(options) => options.run(config),
             Command::CatInfo(options) => options.run(config),
+            Command::EnemyInfo(options) => options.run(config),
+            Command::OrbInfo(options) => options.run(config),
             Command::Gauntlet(options) => options.run(config),
+            Command::Gacha(options) => options.run(config),
+            Command::DiffVersions(options) => options.run(config),
+            Command::CheckPage(options) => options.run(config),
         }
     }
 }
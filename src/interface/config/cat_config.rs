@@ -1,7 +1,7 @@
 //! Deals with the config for cat info.
 
 use serde::{Deserialize, Serialize};
-use std::{fmt::Display, str::FromStr};
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
 use strum::EnumIter;
 
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, EnumIter)]
@@ -119,6 +119,40 @@ pub struct CatConfig {
 
     /// Do you hide stats validation.
     pub stats_hide_validation: bool,
+
+    #[serde(default)]
+    /// Renamed footer categories, mapping the default category name to the name
+    /// used on the wiki. Mapping a category to `""` removes it.
+    pub categories: BTreeMap<String, String>,
+
+    #[serde(default)]
+    /// Ids of collab cats, which get "Collaboration Cats".
+    pub collab_cats: Vec<u32>,
+
+    #[serde(default)]
+    /// Ids of limited cats, which get "Limited Cats".
+    pub limited_cats: Vec<u32>,
+}
+impl CatConfig {
+    /// Get the wiki's name for the `default` category, or `None` if the
+    /// category has been removed.
+    pub fn category_name<'a>(&'a self, default: &'a str) -> Option<&'a str> {
+        match self.categories.get(default) {
+            None => Some(default),
+            Some(name) if name.is_empty() => None,
+            Some(name) => Some(name),
+        }
+    }
+
+    /// Is the cat with `id` a collab cat.
+    pub fn is_collab(&self, id: u32) -> bool {
+        self.collab_cats.contains(&id)
+    }
+
+    /// Is the cat with `id` a limited cat.
+    pub fn is_limited(&self, id: u32) -> bool {
+        self.limited_cats.contains(&id)
+    }
 }
 
 #[cfg(test)]
@@ -136,6 +170,42 @@ mod tests {
         }
     }
 
+    #[test]
+    fn renamed_categories() {
+        let mut config = CatConfig::default();
+        config.categories.insert(
+            "Knockback Cats".to_string(),
+            "Cats with Knockback".to_string(),
+        );
+        config
+            .categories
+            .insert("Cats with Talents".to_string(), String::new());
+
+        assert_eq!(config.category_name("Rare Cats"), Some("Rare Cats"));
+        assert_eq!(
+            config.category_name("Knockback Cats"),
+            Some("Cats with Knockback")
+        );
+        assert_eq!(config.category_name("Cats with Talents"), None);
+    }
+
+    #[test]
+    fn collab_and_limited() {
+        let config: CatConfig = toml::from_str(
+            "stats_template_version = \"current\"\n\
+             stats_hide_validation = false\n\
+             collab_cats = [165]\n\
+             limited_cats = [165, 334]",
+        )
+        .unwrap();
+
+        assert!(config.is_collab(165));
+        assert!(!config.is_collab(334));
+        assert!(config.is_limited(334));
+        assert!(!config.is_limited(0));
+        assert!(config.categories.is_empty());
+    }
+
     #[test]
     fn possible_values_is_correct() {
         let collected = StatsTemplateVersion::iter().collect::<Vec<_>>();
@@ -7,6 +7,9 @@ use serde::{Deserialize, Serialize};
 pub struct StageConfig {
     /// Do you suppress gauntlet mags.
     suppress_gauntlet_mags: bool,
+    #[serde(default)]
+    /// Do you show effective enemy stats.
+    show_effective_stats: bool,
 }
 impl StageConfig {
     /// Do you suppress gauntlet mags.
@@ -18,4 +21,14 @@ impl StageConfig {
     pub fn set_suppress(&mut self, value: bool) {
         self.suppress_gauntlet_mags = value;
     }
+
+    /// Do you show effective enemy stats.
+    pub fn effective_stats(&self) -> bool {
+        self.show_effective_stats
+    }
+
+    /// Set the effective stats flag.
+    pub fn set_effective_stats(&mut self, value: bool) {
+        self.show_effective_stats = value;
+    }
 }
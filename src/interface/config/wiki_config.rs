@@ -2,9 +2,44 @@
 
 use serde::{Deserialize, Serialize};
 
+/// Default location of the wiki.
+pub const DEFAULT_WIKI_URL: &str = "https://battlecats.miraheze.org/wiki";
+/// Default location of the wiki's API.
+pub const DEFAULT_API_URL: &str = "https://battlecats.miraheze.org/w/api.php";
+
 #[derive(Debug, Default, Serialize, Deserialize, Clone)]
 /// Config for interacting with wiki.
 pub struct WikiConfig {
     /// Wiki username.
     pub username: String,
+    #[serde(default)]
+    /// Base URL to get pages from, e.g. `https://battlecats.miraheze.org/wiki`.
+    /// Uses [`DEFAULT_WIKI_URL`] if not set.
+    pub base_url: Option<String>,
+    #[serde(default)]
+    /// URL of the wiki's `api.php`. Uses [`DEFAULT_API_URL`] if not set.
+    pub api_url: Option<String>,
+    #[serde(default)]
+    /// Name to log in with, e.g. `User@bot` for bot passwords. Uses
+    /// [`username`][WikiConfig::username] if not set.
+    pub bot_username: Option<String>,
+    #[serde(default)]
+    /// Bot password (from `Special:BotPasswords`).
+    pub bot_password: Option<String>,
+}
+impl WikiConfig {
+    /// Get base URL of wiki pages.
+    pub fn base_url(&self) -> &str {
+        self.base_url.as_deref().unwrap_or(DEFAULT_WIKI_URL)
+    }
+
+    /// Get URL of the wiki's API.
+    pub fn api_url(&self) -> &str {
+        self.api_url.as_deref().unwrap_or(DEFAULT_API_URL)
+    }
+
+    /// Get name to log in with.
+    pub fn bot_username(&self) -> &str {
+        self.bot_username.as_deref().unwrap_or(&self.username)
+    }
 }
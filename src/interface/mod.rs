@@ -4,6 +4,7 @@ pub mod cli;
 pub mod config;
 pub mod error_handler;
 pub mod scripts;
+pub mod wiki_api;
 
 pub use cli::commands::Cli;
 #[cfg(test)]
@@ -1,14 +1,16 @@
 //! `cat` command.
 
 use crate::{
+    game_data::cat::parsed::cat::Cat,
     interface::{
         cli::{
             base::BaseOptions,
-            cli_util::{CommandExec, ConfigMerge},
+            cli_util::{CommandExec, ConfigMerge, print_json},
             version_opt::VersionOptions,
         },
         config::{Config, cat_config::StatsTemplateVersion},
         scripts::cat_info::cat_info::get_info,
+        wiki_api::publish::{PublishOptions, print_or_publish},
     },
     wiki_data::cat_data::CAT_DATA,
 };
@@ -27,6 +29,9 @@ pub struct CatInfoOptions {
     /// Use validation parameters on cat stats.
     pub use_stats_validation: Option<bool>,
 
+    #[command(flatten)]
+    /// Wiki upload options.
+    pub publish: PublishOptions,
     #[command(flatten)]
     /// Global options.
     pub base: BaseOptions,
@@ -60,7 +65,11 @@ impl CommandExec for CatInfoOptions {
             },
         };
 
-        let info = get_info(id, config).unwrap();
-        println!("{info}");
+        if self.base.is_json() {
+            print_json(&Cat::from_wiki_id(id, &config.version).unwrap());
+            return;
+        }
+        let info = get_info(id, config).unwrap().to_string();
+        print_or_publish(&self.publish, &CAT_DATA.get_cat(id).page, &info, config);
     }
 }
@@ -1,9 +1,240 @@
 //! Footer section of cat page.
 
-use crate::{Config, game_data::cat::parsed::cat::Cat, wikitext::section::Section};
+use crate::{
+    Config,
+    game_data::cat::{
+        ability::{Ability, SurgeType, WaveType},
+        parsed::{
+            cat::Cat,
+            stats::form::CatFormStats,
+            talents::Talents,
+            unitbuy::{AncientEggInfo, EvolutionType},
+        },
+    },
+    interface::config::cat_config::CatConfig,
+    wikitext::section::Section,
+};
+
+/// Category of cats with the ability, if the wiki has one.
+///
+/// Strong Against and Attacks Only are covered by the Anti-X categories, and
+/// immunities and other minor abilities don't have categories.
+const fn ability_category(ability: &Ability) -> Option<&'static str> {
+    let category = match ability {
+        Ability::Knockback { .. } => "Knockback Cats",
+        Ability::Freeze { .. } => "Freeze Cats",
+        Ability::Slow { .. } => "Slow Cats",
+        Ability::Resist => "Resistant Cats",
+        Ability::MassiveDamage => "Massive Damage Cats",
+        Ability::Crit { .. } => "Critical Hit Cats",
+        Ability::DoubleBounty => "Extra Money Cats",
+        Ability::BaseDestroyer => "Base Destroyer Cats",
+        Ability::Wave(wave) => match wave.wtype {
+            WaveType::Wave => "Wave Attack Cats",
+            WaveType::MiniWave => "Mini-Wave Cats",
+        },
+        Ability::Weaken { .. } => "Weaken Cats",
+        Ability::Strengthen { .. } => "Strengthen Cats",
+        Ability::WaveBlocker => "Wave Shield Cats",
+        Ability::ZombieKiller => "Zombie Killer Cats",
+        Ability::WitchKiller => "Witch Killer Cats",
+        Ability::BarrierBreaker { .. } => "Barrier Breaker Cats",
+        Ability::EvaAngelKiller => "EVA Angel Killer Cats",
+        Ability::InsaneResist => "Insanely Tough Cats",
+        Ability::InsaneDamage => "Insane Damage Cats",
+        Ability::SavageBlow { .. } => "Savage Blow Cats",
+        Ability::Dodge { .. } => "Dodge Attack Cats",
+        Ability::Surge(surge) => match surge.stype {
+            SurgeType::Surge => "Surge Attack Cats",
+            SurgeType::MiniSurge => "Mini-Surge Cats",
+        },
+        Ability::Curse { .. } => "Curse Cats",
+        Ability::ShieldPierce { .. } => "Shield Piercing Cats",
+        Ability::ColossusSlayer => "Colossus Slayer Cats",
+        Ability::Soulstrike => "Soulstrike Cats",
+        Ability::BehemothSlayer { .. } => "Behemoth Slayer Cats",
+        Ability::CounterSurge => "Counter-Surge Cats",
+        Ability::SageSlayer => "Sage Slayer Cats",
+        Ability::MetalKiller { .. } => "Metal Killer Cats",
+        Ability::Explosion { .. } => "Explosion Cats",
+        //
+        Ability::StrongAgainst
+        | Ability::TargetsOnly
+        | Ability::Survives { .. }
+        | Ability::Metal
+        | Ability::ImmuneToWave
+        | Ability::ImmuneToKB
+        | Ability::ImmuneToFreeze
+        | Ability::ImmuneToSlow
+        | Ability::ImmuneToWeaken
+        | Ability::ImmuneToBossShockwave
+        | Ability::ImmuneToWarp
+        | Ability::ImmuneToCurse
+        | Ability::ImmuneToToxic
+        | Ability::ImmuneToSurge
+        | Ability::ConjureUnit { .. }
+        | Ability::ImmuneToExplosion => return None,
+    };
+    Some(category)
+}
+
+/// Push `category` if it hasn't already been added.
+fn push_unique(categories: &mut Vec<String>, category: String) {
+    if !categories.contains(&category) {
+        categories.push(category);
+    }
+}
+
+/// Get the default names of every category the cat belongs to.
+///
+/// `talented_stats` are the cat's forms with every talent unlocked, so that
+/// targets and abilities from talents get categories too.
+///
+/// Neither `unitbuy.csv` nor the wiki data says whether a cat is a collab or
+/// limited cat, so those categories come from the ids listed in `cat_config`.
+fn default_categories(
+    cat: &Cat,
+    talents: Option<&Talents>,
+    talented_stats: &[CatFormStats],
+    cat_config: &CatConfig,
+) -> Vec<String> {
+    let misc = &cat.unitbuy.misc;
+    let mut categories = vec![format!("{rarity} Cats", rarity = misc.rarity)];
+
+    if misc.is_summon() {
+        categories.push("Spirits".to_string());
+    }
+    if let AncientEggInfo::Egg { .. } = misc.egg_info {
+        categories.push("Ancient Eggs".to_string());
+    }
+    if cat_config.is_collab(cat.id) {
+        categories.push("Collaboration Cats".to_string());
+    }
+    if cat_config.is_limited(cat.id) {
+        categories.push("Limited Cats".to_string());
+    }
+
+    if cat.forms.amt_forms >= 3 {
+        categories.push("Cats with True Forms".to_string());
+    }
+    if cat.forms.amt_forms >= 4 {
+        categories.push("Cats with Ultra Forms".to_string());
+    }
+    match cat.unitbuy.true_evol.as_ref().map(|evol| &evol.etype) {
+        Some(EvolutionType::Catfruit(_)) => {
+            categories.push("Cats with Catfruit Evolutions".to_string());
+        }
+        Some(EvolutionType::Other) => {
+            categories.push("Cats with Special Evolutions".to_string());
+        }
+        Some(EvolutionType::Levels { .. }) | None => (),
+    }
+
+    let all_stats = || {
+        cat.forms
+            .iter()
+            .map(|(stats, _)| stats)
+            .chain(talented_stats)
+    };
+    for stats in all_stats() {
+        for target in &stats.targets {
+            push_unique(&mut categories, format!("Anti-{target} Cats"));
+        }
+    }
+    for stats in all_stats() {
+        for category in stats.abilities.iter().filter_map(ability_category) {
+            push_unique(&mut categories, category.to_string());
+        }
+    }
+
+    if let Some(talents) = talents {
+        if !talents.normal.is_empty() {
+            categories.push("Cats with Talents".to_string());
+        }
+        if !talents.ultra.is_empty() {
+            categories.push("Cats with Ultra Talents".to_string());
+        }
+    }
+
+    categories
+}
 
 /// Get footer section of page.
-pub fn footer(_cat: &Cat, _config: &Config) -> Section {
-    // TODO categories, see #19
-    Section::blank("{{Cats}}")
+pub fn footer(cat: &Cat, config: &Config) -> Section {
+    let version = config.version.current_version();
+    let talents = cat.get_talents(version);
+    let talented_stats = talents
+        .as_ref()
+        .map(|talents| cat.get_talented_stats(talents, version))
+        .unwrap_or_default();
+    let categories = default_categories(cat, talents.as_ref(), &talented_stats, &config.cat_info);
+
+    let mut buf = String::from("{{Cats}}");
+    for category in &categories {
+        if let Some(name) = config.cat_info.category_name(category) {
+            buf += "\n[[Category:";
+            buf += name;
+            buf += "]]";
+        }
+    }
+
+    Section::blank(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn basic_cat() {
+        let cat = Cat::from_wiki_id(0, &TEST_CONFIG.version).unwrap();
+        let version = TEST_CONFIG.version.current_version();
+        let talents = cat.get_talents(version).unwrap();
+        let talented_stats = cat.get_talented_stats(&talents, version);
+        let categories =
+            default_categories(&cat, Some(&talents), &talented_stats, &TEST_CONFIG.cat_info);
+
+        assert_eq!(categories[0], "Normal Cats");
+        assert!(categories.contains(&"Cats with True Forms".to_string()));
+        assert!(!categories.contains(&"Spirits".to_string()));
+        assert!(!categories.contains(&"Collaboration Cats".to_string()));
+    }
+
+    #[test]
+    fn collab_and_limited_cats() {
+        let mut cat_config = CatConfig::default();
+        cat_config.collab_cats.push(0);
+        cat_config.limited_cats.push(0);
+
+        let cat = Cat::from_wiki_id(0, &TEST_CONFIG.version).unwrap();
+        let categories = default_categories(&cat, None, &[], &cat_config);
+        assert_eq!(
+            &categories[..3],
+            ["Normal Cats", "Collaboration Cats", "Limited Cats"]
+        );
+    }
+
+    #[test]
+    fn ability_categories() {
+        assert_eq!(
+            ability_category(&Ability::Knockback { chance: 100 }),
+            Some("Knockback Cats")
+        );
+        assert_eq!(ability_category(&Ability::StrongAgainst), None);
+        assert_eq!(ability_category(&Ability::ImmuneToWarp), None);
+    }
+
+    #[test]
+    fn renamed_category() {
+        let mut config = TEST_CONFIG.clone();
+        config
+            .cat_info
+            .categories
+            .insert("Normal Cats".to_string(), "Basic Cats".to_string());
+
+        let cat = Cat::from_wiki_id(0, &config.version).unwrap();
+        let footer = footer(&cat, &config).to_string();
+        assert!(footer.starts_with("{{Cats}}\n[[Category:Basic Cats]]"));
+    }
 }
@@ -154,7 +154,7 @@ pub fn get_form(
     let knockback = format!(
         "{kb} {times}",
         kb = stats.kb.to_formatted_string(&Locale::en),
-        times = plural(stats.kb, "time", "times")
+        times = plural(u32::from(stats.kb), "time", "times")
     );
     let animation = {
         let (fore_f, fore_s) = time_repr(u32::from(foreswing));
@@ -184,14 +184,14 @@ pub fn stats_1o0(cat: &Cat, config: &Config) -> Template {
         .version
         .current_version()
         .get_cached_file::<EquipmentSlotContainer>();
-    let cat_has_slots = match slots.get_slot_item(cat.id.try_into().unwrap()) {
-        None => false,
-        Some(slot) => slot.amt_slots > 0,
-    };
+    let amt_slots = slots.amt_slots(cat.id.try_into().unwrap());
 
     t.push_params(TemplateParameter::new("Lv.MAX", max_level));
     t.push_params(TemplateParameter::new("Scaling", scaling));
-    t.push_params(TemplateParameter::new("Orb", cat_has_slots.to_string()));
+    t.push_params(TemplateParameter::new("Orb", (amt_slots > 0).to_string()));
+    if amt_slots > 0 {
+        t.push_params(TemplateParameter::new("Orb slots", amt_slots.to_string()));
+    }
     if !config.cat_info.stats_hide_validation {
         t.push_params(TemplateParameter::new("validation", "on"));
     }
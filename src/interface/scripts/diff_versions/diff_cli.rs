@@ -0,0 +1,76 @@
+//! `diff-versions` command.
+
+use super::diff_versions::diff_versions;
+use crate::{
+    game_data::version::Version,
+    interface::{
+        cli::{
+            base::BaseOptions,
+            cli_util::{CommandExec, ConfigMerge, print_json},
+            version_opt::VersionOptions,
+        },
+        config::Config,
+    },
+};
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Args, PartialEq)]
+/// Diff versions options.
+pub struct DiffVersionsOptions {
+    /// Root directory of the old version's decrypted files.
+    pub old: PathBuf,
+    /// Root directory of the new version's decrypted files.
+    pub new: PathBuf,
+
+    #[command(flatten)]
+    /// Global options.
+    pub base: BaseOptions,
+    #[command(flatten)]
+    /// Version options.
+    pub version: VersionOptions,
+}
+impl ConfigMerge for DiffVersionsOptions {
+    fn merge(&self, config: &mut Config) {
+        self.base.merge(config);
+        self.version.merge(config);
+    }
+}
+impl CommandExec for DiffVersionsOptions {
+    fn exec(&self, config: &Config) {
+        let lang = config.version.lang();
+        let old = Version::new(self.old.clone(), lang, None);
+        let new = Version::new(self.new.clone(), lang, None);
+
+        let diff = diff_versions(&old, &new);
+        if self.base.is_json() {
+            print_json(&diff);
+        } else {
+            println!("{diff}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::cli::commands::{Cli, Command};
+    use clap::Parser;
+
+    #[test]
+    fn basic_diff() {
+        const ARGS: [&str; 4] = ["run_program", "diff", "old", "new"];
+        let cli = Cli::parse_from(ARGS.iter());
+        assert_eq!(
+            cli,
+            Cli {
+                command: Command::DiffVersions(DiffVersionsOptions {
+                    old: "old".into(),
+                    new: "new".into(),
+                    base: Default::default(),
+                    version: Default::default(),
+                }),
+            }
+        );
+    }
+}
@@ -0,0 +1,446 @@
+//! Compare the data of two versions of the game.
+//!
+//! Items are compared by their serialised values, so any type that implements
+//! [`Serialize`] can be diffed without needing to implement [`PartialEq`].
+
+use crate::{
+    game_data::{
+        cat::raw::{
+            combo::{ComboData, CombosDataContainer},
+            combo_local::ComboNames,
+            stats::{CombinedCatData, get_cat_files, read_data_file},
+            talents::{TalentLine, TalentsContainer},
+            unitbuy::{UnitBuyContainer, UnitBuyRaw},
+        },
+        item::gatya_item::GatyaItems,
+        map::cached::{
+            map_option::MapOption, score_bonus::ScoreBonuses, special_rules::SpecialRules,
+        },
+        meta::stage::{stage_id::StageID, stage_types::get_stage_type, variant::StageVariantID},
+        stage::raw::{stage_data::csv_types::RawCSVData, stage_index::StageIndex},
+        version::Version,
+    },
+    wiki_data::{cat_data::CAT_DATA, rewards::TREASURE_DATA},
+};
+use serde::Serialize;
+use serde_json::Value;
+use std::{
+    collections::BTreeMap,
+    fmt::{Display, Write},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+/// How an item differs between the two versions.
+pub enum ChangeType {
+    /// Item only exists in the new version.
+    Added,
+    /// Item only exists in the old version.
+    Removed,
+    /// Item exists in both versions but is different.
+    Changed,
+}
+impl ChangeType {
+    const fn symbol(self) -> char {
+        match self {
+            Self::Added => '+',
+            Self::Removed => '-',
+            Self::Changed => '~',
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+/// Single item that differs between versions.
+pub struct Change {
+    /// Readable identifier of the item (e.g. stage id).
+    pub id: String,
+    /// Data that the item comes from (e.g. `Map_option.csv`).
+    pub source: &'static str,
+    /// How the item differs.
+    pub change: ChangeType,
+}
+
+#[derive(Debug, PartialEq, Serialize)]
+/// Changes grouped under a single heading (e.g. stage type or cat).
+pub struct DiffGroup {
+    /// Name of the group.
+    pub name: String,
+    /// Changes in the group.
+    pub changes: Vec<Change>,
+}
+
+#[derive(Debug, Default, Serialize)]
+/// All differences between two versions.
+pub struct VersionDiff {
+    /// Stages, grouped by stage type.
+    pub stages: Vec<DiffGroup>,
+    /// Map data, grouped by stage type.
+    pub maps: Vec<DiffGroup>,
+    /// Cat data, grouped by cat id.
+    pub cats: Vec<DiffGroup>,
+    /// Combos.
+    pub combos: Vec<Change>,
+    /// Items added in the new version that aren't in Treasures.csv, so need a
+    /// wiki name.
+    pub items: Vec<Change>,
+}
+impl VersionDiff {
+    /// Are the two versions identical.
+    pub fn is_empty(&self) -> bool {
+        self.stages.is_empty()
+            && self.maps.is_empty()
+            && self.cats.is_empty()
+            && self.combos.is_empty()
+            && self.items.is_empty()
+    }
+}
+
+fn write_change(f: &mut std::fmt::Formatter<'_>, change: &Change) -> std::fmt::Result {
+    writeln!(
+        f,
+        "  {symbol} {id} ({source})",
+        symbol = change.change.symbol(),
+        id = change.id,
+        source = change.source
+    )
+}
+
+fn write_groups(
+    f: &mut std::fmt::Formatter<'_>,
+    heading: &str,
+    groups: &[DiffGroup],
+) -> std::fmt::Result {
+    if groups.is_empty() {
+        return Ok(());
+    }
+
+    writeln!(f, "== {heading} ==")?;
+    for group in groups {
+        writeln!(f, "{name}:", name = group.name)?;
+        for change in &group.changes {
+            write_change(f, change)?;
+        }
+    }
+    f.write_char('\n')
+}
+
+impl Display for VersionDiff {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.is_empty() {
+            return f.write_str("No differences found.");
+        }
+
+        write_groups(f, "Stages", &self.stages)?;
+        write_groups(f, "Maps", &self.maps)?;
+        write_groups(f, "Cats", &self.cats)?;
+        if !self.combos.is_empty() {
+            writeln!(f, "== Combos ==")?;
+            for change in &self.combos {
+                write_change(f, change)?;
+            }
+        }
+        if !self.items.is_empty() {
+            writeln!(f, "== Items missing from Treasures.csv ==")?;
+            for change in &self.items {
+                write_change(f, change)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Serialise all items into a map.
+fn to_values<K: Ord, V: Serialize>(items: impl IntoIterator<Item = (K, V)>) -> BTreeMap<K, Value> {
+    items
+        .into_iter()
+        .map(|(k, v)| {
+            let value = serde_json::to_value(v).expect("data should always be serialisable");
+            (k, value)
+        })
+        .collect()
+}
+
+/// Compare two sets of keyed items. Result is sorted by key.
+fn diff_items<K: Ord, V: Serialize>(
+    old: impl IntoIterator<Item = (K, V)>,
+    new: impl IntoIterator<Item = (K, V)>,
+) -> Vec<(K, ChangeType)> {
+    let old = to_values(old);
+    let mut new = to_values(new);
+
+    let mut changes = vec![];
+    for (key, old_value) in old {
+        match new.remove(&key) {
+            None => changes.push((key, ChangeType::Removed)),
+            Some(new_value) if new_value != old_value => changes.push((key, ChangeType::Changed)),
+            Some(_) => (),
+        }
+    }
+    changes.extend(new.into_keys().map(|key| (key, ChangeType::Added)));
+    changes.sort_by(|a, b| a.0.cmp(&b.0));
+
+    changes
+}
+
+/// Group changes. `group` gets the group's sort key and `group_name` gets the
+/// name of a group from its sort key.
+fn group_changes<K>(
+    changes: impl IntoIterator<Item = (K, Change)>,
+    group: impl Fn(&K) -> u32,
+    group_name: impl Fn(u32) -> String,
+) -> Vec<DiffGroup> {
+    let mut groups = BTreeMap::<u32, Vec<Change>>::new();
+    for (key, change) in changes {
+        groups.entry(group(&key)).or_default().push(change);
+    }
+
+    groups
+        .into_iter()
+        .map(|(key, changes)| DiffGroup {
+            name: group_name(key),
+            changes,
+        })
+        .collect()
+}
+
+/// Readable name of the stage type with variant id `variant`.
+fn variant_name(variant: u32) -> String {
+    match StageVariantID::from_repr(variant) {
+        Some(variant) => get_stage_type(variant).data.name.to_string(),
+        None => format!("Unknown stage type {variant}"),
+    }
+}
+
+/// Compare every stage file.
+fn diff_stages(old: &Version, new: &Version) -> Vec<DiffGroup> {
+    type Key = (u32, u32, u32);
+    fn items(version: &Version) -> impl Iterator<Item = (Key, &RawCSVData)> {
+        version
+            .get_cached_file::<StageIndex>()
+            .iter()
+            .map(|(id, data)| ((id.variant().num(), id.map().num(), id.num()), data))
+    }
+
+    let changes = diff_items(items(old), items(new)).into_iter().map(
+        |(key @ (variant, map, stage), change)| {
+            let id = StageID::from_numbers(variant, map, stage).to_string();
+            let change = Change {
+                id,
+                source: "stage data",
+                change,
+            };
+            (key, change)
+        },
+    );
+
+    group_changes(changes, |(variant, _, _)| *variant, variant_name)
+}
+
+/// Compare map data files.
+fn diff_maps(old: &Version, new: &Version) -> Vec<DiffGroup> {
+    fn with_source(
+        changes: Vec<(u32, ChangeType)>,
+        source: &'static str,
+    ) -> impl Iterator<Item = (u32, Change)> {
+        changes.into_iter().map(move |(mapid, change)| {
+            let id = format!("{:03}-{:03}", mapid / 1000, mapid % 1000);
+            (mapid, Change { id, source, change })
+        })
+    }
+
+    let map_option = diff_items(
+        old.get_cached_file::<MapOption>().iter(),
+        new.get_cached_file::<MapOption>().iter(),
+    );
+    let special_rules = diff_items(
+        old.get_cached_file::<SpecialRules>().iter(),
+        new.get_cached_file::<SpecialRules>().iter(),
+    );
+    let score_bonus = diff_items(
+        old.get_cached_file::<ScoreBonuses>().iter(),
+        new.get_cached_file::<ScoreBonuses>().iter(),
+    );
+
+    let mut changes = with_source(map_option, "Map_option.csv")
+        .chain(with_source(special_rules, "SpecialRulesMap.json"))
+        .chain(with_source(score_bonus, "ScoreBonusMap.json"))
+        .collect::<Vec<_>>();
+    changes.sort_by_key(|(mapid, _)| *mapid);
+
+    group_changes(changes, |mapid| mapid / 1000, variant_name)
+}
+
+/// Get the cat's id from its data file name (`unit001.csv` = 0).
+fn cat_file_id(file_name: &str) -> Option<u32> {
+    let num = file_name.strip_prefix("unit")?.strip_suffix(".csv")?;
+    num.parse::<u32>().ok()?.checked_sub(1)
+}
+
+/// Readable name of the cat with wiki id `id`.
+fn cat_name(id: u32) -> String {
+    match CAT_DATA.try_get_cat(id as usize) {
+        Some(cat) => format!("{id:03} {name}", name = cat.normal),
+        None => format!("{id:03}"),
+    }
+}
+
+/// Compare unitbuy, cat stats and talents.
+fn diff_cats(old: &Version, new: &Version) -> Vec<DiffGroup> {
+    fn with_source(
+        changes: Vec<(u32, ChangeType)>,
+        source: &'static str,
+    ) -> impl Iterator<Item = (u32, Change)> {
+        changes.into_iter().map(move |(cat_id, change)| {
+            let id = cat_name(cat_id);
+            (cat_id, Change { id, source, change })
+        })
+    }
+
+    fn unitbuy(version: &Version) -> impl Iterator<Item = (u32, &UnitBuyRaw)> {
+        (0..).zip(version.get_cached_file::<UnitBuyContainer>().iter())
+    }
+    fn stats(version: &Version) -> Vec<(u32, Vec<CombinedCatData>)> {
+        get_cat_files(version)
+            .filter_map(|file_name| {
+                let id = cat_file_id(&file_name)?;
+                let forms = read_data_file(&file_name, version).collect();
+                Some((id, forms))
+            })
+            .collect()
+    }
+    fn talents(version: &Version) -> impl Iterator<Item = (u32, &TalentLine)> {
+        version
+            .get_cached_file::<TalentsContainer>()
+            .iter()
+            .map(|talents| (u32::from(talents.fixed.id), talents))
+    }
+
+    let mut changes = with_source(diff_items(unitbuy(old), unitbuy(new)), "unitbuy.csv")
+        .chain(with_source(diff_items(stats(old), stats(new)), "stats"))
+        .chain(with_source(
+            diff_items(talents(old), talents(new)),
+            "SkillAcquisition.csv",
+        ))
+        .collect::<Vec<_>>();
+    changes.sort_by_key(|(id, _)| *id);
+
+    group_changes(changes, |id| *id, cat_name)
+}
+
+/// Compare cat combos.
+fn diff_combos(old: &Version, new: &Version) -> Vec<Change> {
+    fn combos(version: &Version) -> impl Iterator<Item = (usize, &ComboData)> {
+        version
+            .get_cached_file::<CombosDataContainer>()
+            .combos()
+            .iter()
+            .enumerate()
+    }
+
+    let old_names = old.get_cached_file::<ComboNames>();
+    let new_names = new.get_cached_file::<ComboNames>();
+
+    diff_items(combos(old), combos(new))
+        .into_iter()
+        .map(|(i, change)| {
+            let name = match change {
+                ChangeType::Removed => old_names.combo_name(i),
+                _ => new_names.combo_name(i),
+            };
+            let id = match name {
+                Some(name) => format!("{i:03} {name}"),
+                None => format!("{i:03}"),
+            };
+            Change {
+                id,
+                source: "NyancomboData.csv",
+                change,
+            }
+        })
+        .collect()
+}
+
+/// Get items that were added in `new` and don't have a wiki name.
+fn diff_items_missing_names(old: &Version, new: &Version) -> Vec<Change> {
+    let old_amt = old.get_cached_file::<GatyaItems>().ids().count();
+    let new_items = new.get_cached_file::<GatyaItems>();
+
+    new_items
+        .ids()
+        .skip(old_amt)
+        .filter(|id| !TREASURE_DATA.has_treasure(*id))
+        .map(|id| {
+            let id = match new_items.name(id) {
+                Some(name) => format!("{id:03} {name}"),
+                None => format!("{id:03}"),
+            };
+            Change {
+                id,
+                source: "Gatyaitembuy.csv",
+                change: ChangeType::Added,
+            }
+        })
+        .collect()
+}
+
+/// Get all differences between `old` and `new`.
+pub fn diff_versions(old: &Version, new: &Version) -> VersionDiff {
+    VersionDiff {
+        stages: diff_stages(old, new),
+        maps: diff_maps(old, new),
+        cats: diff_cats(old, new),
+        combos: diff_combos(old, new),
+        items: diff_items_missing_names(old, new),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn diff_basic() {
+        let old = [(0, "a"), (1, "b"), (2, "c")];
+        let new = [(1, "b"), (2, "d"), (3, "e")];
+        assert_eq!(
+            diff_items(old, new),
+            [
+                (0, ChangeType::Removed),
+                (2, ChangeType::Changed),
+                (3, ChangeType::Added)
+            ]
+        );
+    }
+
+    #[test]
+    fn cat_files() {
+        assert_eq!(cat_file_id("unit001.csv"), Some(0));
+        assert_eq!(cat_file_id("unit826.csv"), Some(825));
+        assert_eq!(cat_file_id("unitbuy.csv"), None);
+    }
+
+    #[test]
+    fn display() {
+        let diff = VersionDiff {
+            maps: group_changes(
+                [(
+                    0,
+                    Change {
+                        id: "000-000".into(),
+                        source: "Map_option.csv",
+                        change: ChangeType::Changed,
+                    },
+                )],
+                |_| 0,
+                |_| "Stories of Legend".into(),
+            ),
+            ..Default::default()
+        };
+        assert_eq!(
+            diff.to_string(),
+            "== Maps ==\nStories of Legend:\n  ~ 000-000 (Map_option.csv)\n\n"
+        );
+        assert_eq!(VersionDiff::default().to_string(), "No differences found.");
+    }
+}
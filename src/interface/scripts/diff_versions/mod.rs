@@ -0,0 +1,4 @@
+//! Find what changed between two versions of the game.
+
+pub mod diff_cli;
+pub mod diff_versions;
@@ -2,9 +2,10 @@
 
 use super::section::SectionRef;
 use crate::game_data::meta::stage::stage_id::StageID;
+use serde::Serialize;
 use std::{borrow::Cow, collections::HashSet};
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Represents a stage for encounters.
 pub struct Stage<'a> {
     /// Name of stage.
@@ -31,7 +32,7 @@ impl Stage<'_> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Represents a contiguous block of stages of the same display type with the
 /// same map num.
 pub struct Chapter<'a> {
@@ -66,7 +67,7 @@ impl<'a> Chapter<'a> {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Serialize)]
 /// Represents a section of the encounters.
 pub struct Group<'a> {
     /// Section reference.
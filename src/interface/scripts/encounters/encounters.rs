@@ -3,7 +3,6 @@
 use super::{
     chapter::{Chapter, Group, Stage},
     section::{DisplayType, SectionRef},
-    zoutbreak::manual_zoutbreak_replace,
 };
 use crate::{
     game_data::{
@@ -14,15 +13,18 @@ use crate::{
             variant::StageVariantID as T,
         },
         stage::{
-            parsed::stage_enemy::StageEnemy, raw::stage_data::StageData,
-            stage_util::get_stage_files,
+            parsed::stage_enemy::StageEnemy,
+            raw::{stage_data::StageData, stage_index::StageIndex},
         },
         version::Version,
     },
-    interface::{config::Config, error_handler::InfallibleWrite},
+    interface::error_handler::InfallibleWrite,
     regex_handler::static_regex,
     wiki_data::stage_wiki_data::STAGE_WIKI_DATA,
-    wikitext::text_utils::{OLD_OR_REMOVED_DETECT, OLD_OR_REMOVED_SUB},
+    wikitext::{
+        section::Section,
+        text_utils::{OLD_OR_REMOVED_DETECT, OLD_OR_REMOVED_SUB},
+    },
 };
 use either::Either::{Left, Right};
 use num_format::{Locale, WriteFormatted};
@@ -367,6 +369,12 @@ fn get_section_map<'a>(
 /// If enemy has always appeared at a certain mag, then remove mags after stage
 /// names and replace with single message at top.
 fn always_appeared_at(buf: &mut String) {
+    // outbreak magnifications are only given per chapter, so the enemy
+    // won't have always appeared at one magnification
+    if static_regex(r"Outbreaks\]\]===\nStrength magnifications? ").is_match(buf) {
+        return;
+    }
+
     let percentage_pattern = r" \([\d,%\s]+%\)\n";
     let re = static_regex(percentage_pattern);
     // This should probably be done in the actual code but oh well
@@ -418,9 +426,8 @@ fn always_appeared_at(buf: &mut String) {
 }
 
 /// Post-process the buffer and apply some text transformations.
-fn cleanup(buf: &mut String, abs_enemy_id: u32) {
+fn cleanup(buf: &mut String) {
     always_appeared_at(buf);
-    manual_zoutbreak_replace(buf, abs_enemy_id);
 }
 
 /// Write the section text of an encounter group. Includes trailing newline.
@@ -430,6 +437,20 @@ fn write_encounter_group(buf: &mut String, group: Group<'_>) {
                     Chapter 2, and 400% in Chapter 3.\n";
     }
 
+    if *group.sref.section().display_type() == DisplayType::Outbreak {
+        let chapters = group
+            .chapters
+            .into_iter()
+            .filter(|chapter| !chapter.stages.is_empty())
+            .map(Chapter::dedupped)
+            .collect::<Vec<_>>();
+        if !chapters.is_empty() {
+            group.sref.section().fmt_outbreak(buf, chapters);
+            *buf += "\n";
+        }
+        return;
+    }
+
     for mut chapter in group.chapters {
         if chapter.stages.is_empty() {
             log::info!("{:?} has no valid stages.", chapter.chapter_name);
@@ -453,17 +474,15 @@ fn write_encounter_group(buf: &mut String, group: Group<'_>) {
 }
 
 /// Get an iterator over all stages in the version.
-fn get_stages(version: &Version) -> impl Iterator<Item = StageData<'_>> {
-    get_stage_files(version)
-        .map(|file_name| StageData::from_file_name(&file_name, version).unwrap())
+pub fn get_stages(version: &Version) -> impl Iterator<Item = StageData<'_>> {
+    version.get_cached_file::<StageIndex>().stage_data(version)
 }
 
-/// temp
-pub fn do_thing(wiki_id: u32, config: &Config) {
+/// Get the encounter groups of the enemy with wiki id `wiki_id`, using the
+/// pre-parsed `all_stages` (see [`get_stages`]).
+pub fn get_groups<'a>(wiki_id: u32, all_stages: &'a [StageData]) -> Vec<Group<'a>> {
     let abs_enemy_id = wiki_id + 2;
 
-    let all_stages = get_stages(config.version.current_version()).collect::<Vec<_>>();
-
     let mut encounters = all_stages
         .iter()
         .filter(|s| stage_contains_enemy(abs_enemy_id, s))
@@ -471,9 +490,21 @@ pub fn do_thing(wiki_id: u32, config: &Config) {
     sort_encounters(&mut encounters);
 
     let section_map = get_section_map(&encounters);
-    let groups = get_encounter_groups(section_map, abs_enemy_id);
+    get_encounter_groups(section_map, abs_enemy_id)
+}
+
+/// Get the Encounters section of the enemy with wiki id `wiki_id`, using the
+/// pre-parsed `all_stages` (see [`get_stages`]).
+pub fn get_encounters(wiki_id: u32, all_stages: &[StageData]) -> String {
+    encounters_section(wiki_id, all_stages).to_string()
+}
+
+/// Get the Encounters [`Section`] of the enemy with wiki id `wiki_id`, using
+/// the pre-parsed `all_stages` (see [`get_stages`]).
+pub fn encounters_section(wiki_id: u32, all_stages: &[StageData]) -> Section {
+    let groups = get_groups(wiki_id, all_stages);
 
-    let mut buf = String::from("==Encounters==\n{{Collapsible}}");
+    let mut buf = String::from("{{Collapsible}}");
     for group in groups {
         if group.chapters.is_empty() {
             continue;
@@ -490,14 +521,13 @@ pub fn do_thing(wiki_id: u32, config: &Config) {
     }
     buf += "</div>";
 
-    cleanup(&mut buf, abs_enemy_id);
+    cleanup(&mut buf);
 
-    println!("{buf}");
+    Section::h2("Encounters", buf)
 
     /*
     ## extensions
     - [ ] analyse all stages to see if has same mag in all
-    - [ ] analyse eoc outbreaks
     */
 }
 
@@ -1,17 +1,24 @@
 //! `encounters` command.
 
 use crate::{
+    game_data::stage::raw::stage_data::StageData,
     interface::{
         cli::{
             base::BaseOptions,
-            cli_util::{CommandExec, ConfigMerge},
+            cli_util::{CommandExec, ConfigMerge, file_names, print_json},
             version_opt::VersionOptions,
         },
         config::Config,
+        scripts::encounters::{
+            chapter::Group,
+            encounters::{get_encounters, get_groups, get_stages},
+        },
     },
     wiki_data::enemy_data::ENEMY_DATA,
 };
-use clap::{Args, command};
+use clap::Args;
+use serde::Serialize;
+use std::{fs, path::PathBuf};
 
 #[derive(Debug, Args, PartialEq)]
 /// Encounters options.
@@ -19,6 +26,12 @@ pub struct EncountersOptions {
     /// Which units to get encounters for.
     pub names: Vec<String>,
 
+    #[arg(short, long)]
+    /// Write each enemy's section to `{output_dir}/{enemy name}.txt` (or
+    /// `.json`) instead of printing it. Enemies that share a name get their id
+    /// added to the file name.
+    pub output_dir: Option<PathBuf>,
+
     #[command(flatten)]
     /// Global options.
     pub base: BaseOptions,
@@ -34,15 +47,117 @@ impl ConfigMerge for EncountersOptions {
     }
 }
 
+/// Get the enemy's wiki id from its name or id.
+fn get_id(name_or_id: &str) -> u32 {
+    match ENEMY_DATA.get_id_from_name(name_or_id) {
+        Some(id) => *id,
+        None => match name_or_id.parse() {
+            Ok(id) => id,
+            Err(_) => panic!("{name_or_id:?} is not a valid number or enemy name!"),
+        },
+    }
+}
+
+/// Get the file name of every enemy in `ids`.
+fn enemy_file_names(ids: &[u32], extension: &str) -> Vec<String> {
+    let enemies = ids
+        .iter()
+        .map(|&id| (ENEMY_DATA.get_common_name(id), id))
+        .collect::<Vec<_>>();
+    file_names(&enemies, extension)
+}
+
+#[derive(Debug, Serialize)]
+/// Encounters of a single enemy, used for JSON output.
+struct EnemyEncounters<'a> {
+    /// Enemy's wiki id.
+    id: u32,
+    /// Enemy's common name.
+    name: &'a str,
+    /// Encounter groups.
+    groups: Vec<Group<'a>>,
+}
+impl EncountersOptions {
+    /// Print or write encounter groups as JSON.
+    fn exec_json(&self, ids: &[u32], all_stages: &[StageData]) {
+        let encounters = ids.iter().map(|&id| EnemyEncounters {
+            id,
+            name: ENEMY_DATA.get_common_name(id),
+            groups: get_groups(id, all_stages),
+        });
+
+        let Some(dir) = &self.output_dir else {
+            print_json(&encounters.collect::<Vec<_>>());
+            return;
+        };
+        for (encounters, file_name) in encounters.zip(enemy_file_names(ids, "json")) {
+            let path = dir.join(file_name);
+            let json = serde_json::to_string_pretty(&encounters)
+                .expect("data should always be serialisable");
+            fs::write(&path, json).unwrap_or_else(|e| panic!("Couldn't write to {path:?}: {e}"));
+            log::info!("Written encounters to {path:?}.");
+        }
+    }
+}
+
 impl CommandExec for EncountersOptions {
     fn exec(&self, config: &Config) {
-        log::warn!("This currently only works on the first enemy");
-        let name_or_id = &self.names[0];
-        let id = match ENEMY_DATA.get_id_from_name(name_or_id) {
-            None => name_or_id.parse().unwrap(),
-            Some(id) => *id,
-        };
+        let ids = self
+            .names
+            .iter()
+            .map(|name| get_id(name))
+            .collect::<Vec<_>>();
+        let all_stages = get_stages(config.version.current_version()).collect::<Vec<_>>();
+
+        if let Some(dir) = &self.output_dir {
+            fs::create_dir_all(dir).expect("Couldn't create output directory.");
+        }
+
+        if self.base.is_json() {
+            self.exec_json(&ids, &all_stages);
+            return;
+        }
+
+        let file_names = enemy_file_names(&ids, "txt");
+        for (id, file_name) in ids.into_iter().zip(file_names) {
+            let encounters = get_encounters(id, &all_stages);
+            match &self.output_dir {
+                None if self.names.len() == 1 => println!("{encounters}"),
+                None => println!(
+                    "{name}:\n{encounters}\n",
+                    name = ENEMY_DATA.get_common_name(id)
+                ),
+                Some(dir) => {
+                    let path = dir.join(file_name);
+                    fs::write(&path, encounters)
+                        .unwrap_or_else(|e| panic!("Couldn't write to {path:?}: {e}"));
+                    log::info!("Written encounters to {path:?}.");
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::cli::commands::{Cli, Command};
+    use clap::Parser;
 
-        crate::interface::scripts::encounters::encounters::do_thing(id, config);
+    #[test]
+    fn multiple_enemies() {
+        const ARGS: [&str; 6] = ["run_program", "encounters", "0", "1", "-o", "out"];
+        let cli = Cli::parse_from(ARGS.iter());
+        assert_eq!(
+            cli,
+            Cli {
+                command: Command::Encounters(EncountersOptions {
+                    names: ["0".into(), "1".into()].into(),
+                    output_dir: Some("out".into()),
+                    base: Default::default(),
+                    version: Default::default(),
+                }),
+            }
+        );
     }
 }
@@ -4,4 +4,3 @@ pub mod chapter;
 pub mod encounters;
 pub mod encounters_cli;
 pub mod section;
-pub mod zoutbreak;
@@ -5,7 +5,11 @@ use crate::{
     game_data::meta::stage::{stage_id::StageID, variant::StageVariantID as T},
     wiki_data::stage_wiki_data::STAGE_WIKI_DATA,
 };
-use std::fmt::Write;
+use serde::Serialize;
+use std::{
+    collections::{BTreeMap, BTreeSet, btree_map::Entry},
+    fmt::Write,
+};
 use strum::EnumIter;
 
 #[derive(Debug, PartialEq)]
@@ -19,6 +23,9 @@ pub enum DisplayType {
     Flat,
     /// Main chapters; require extra logic.
     Custom,
+    /// Zombie Outbreaks; stages from every chapter are collapsed into one list
+    /// (see [`EncountersSection::fmt_outbreak`]).
+    Outbreak,
     /// Format like Normal but give a warning to the user.
     Warn,
     /// Don't parse this at all.
@@ -26,6 +33,73 @@ pub enum DisplayType {
 }
 type D = DisplayType;
 
+/// Index of the Moon stage in each EoC Outbreak chapter.
+const EOC_OUTBREAK_MOON: u32 = 47;
+
+/// Is the stage in a chapter of EoC, ItF or CotC Outbreaks.
+fn is_chapter_outbreak(id: &StageID) -> bool {
+    matches!(
+        id.variant(),
+        T::EocOutbreak | T::ItfOutbreak | T::CotcOutbreak
+    )
+}
+
+/// Remove the chapter suffix from a main chapter or outbreak stage's name
+/// (e.g. `"[[Korea (Empire of Cats)|Korea]] (Z1)"`).
+fn strip_chapter_suffix(name: &str) -> &str {
+    &name[..name.len() - " (Z3)".len()]
+}
+
+/// Is the stage the Moon stage of an EoC Outbreak chapter.
+fn is_outbreak_moon(id: &StageID) -> bool {
+    id.variant() == T::EocOutbreak && id.num() == EOC_OUTBREAK_MOON
+}
+
+/// Get the magnification of each outbreak chapter the enemy appears in. If the
+/// enemy appears at multiple magnifications in the same chapter, or doesn't
+/// appear in the same stages in every chapter (ignoring EoC's Moon), then
+/// returns [`None`].
+fn outbreak_chapter_mags<'a>(chapters: &'a [Chapter]) -> Option<BTreeMap<u32, &'a str>> {
+    let mut chapter_mags = BTreeMap::new();
+    let mut chapter_stages = BTreeMap::<u32, BTreeSet<u32>>::new();
+    for stage in chapters.iter().flat_map(|chapter| &chapter.stages) {
+        if !is_chapter_outbreak(stage.id()) {
+            continue;
+        }
+        let mags = stage.mags.as_str();
+        if mags.contains(", ") {
+            return None;
+        }
+
+        let chap = stage.id().map().num();
+        match chapter_mags.entry(chap) {
+            Entry::Vacant(entry) => {
+                entry.insert(mags);
+            }
+            Entry::Occupied(entry) if *entry.get() != mags => return None,
+            Entry::Occupied(_) => (),
+        }
+
+        let stages = chapter_stages.entry(chap).or_default();
+        if !is_outbreak_moon(stage.id()) {
+            stages.insert(stage.id().num());
+        }
+    }
+
+    let mut stage_sets = chapter_stages.values();
+    if let Some(first) = stage_sets.next()
+        && stage_sets.any(|stages| stages != first)
+    {
+        return None;
+    }
+
+    if chapter_mags.is_empty() {
+        None
+    } else {
+        Some(chapter_mags)
+    }
+}
+
 #[derive(Debug, PartialEq)]
 /// Section of unit encounters.
 ///
@@ -70,7 +144,7 @@ impl EncountersSection {
                 "Stage {chap}-{stage}: {name}",
                 chap = id.map().num() % 3 + 1,
                 stage = id.num() + 1,
-                name = &name[..name.len() - " (N1)".len()]
+                name = strip_chapter_suffix(name)
             )
             .unwrap();
             return;
@@ -105,7 +179,7 @@ impl EncountersSection {
             "Stage {chap}-{stage}: {name}",
             chap = id.map().num() + 1,
             stage = id.num() + 1,
-            name = &name[..name.len() - " (Z3)".len()]
+            name = strip_chapter_suffix(name)
         )
         .unwrap();
     }
@@ -128,7 +202,7 @@ impl EncountersSection {
 
                 write!(buf, ": {stage_name}").unwrap();
             }
-            D::Custom => Self::fmt_encounter_custom(buf, id, stage_name),
+            D::Custom | D::Outbreak => Self::fmt_encounter_custom(buf, id, stage_name),
         }
 
         if !(mags.is_empty()) {
@@ -157,7 +231,7 @@ impl EncountersSection {
                     self.fmt_encounter(buf, stage.id(), stage.stage_name, &stage.mags);
                 }
             }
-            D::Story | D::Flat | D::Custom => {
+            D::Story | D::Flat | D::Custom | D::Outbreak => {
                 // Custom is being done like this since it's only main chaps at
                 // the moment
                 for stage in chapter.stages {
@@ -185,6 +259,78 @@ impl EncountersSection {
             }
         }
     }
+
+    /// Write every chapter of a Zombie Outbreak group.
+    ///
+    /// If the enemy appears at a single magnification in each chapter and in
+    /// the same stages in every chapter, then stages from all chapters are
+    /// collapsed into one list and the magnifications are written above it.
+    /// Otherwise each chapter is written separately.
+    pub fn fmt_outbreak(&self, buf: &mut String, chapters: Vec<Chapter>) {
+        assert_eq!(self.display_type, D::Outbreak);
+        let Some(chapter_mags) = outbreak_chapter_mags(&chapters) else {
+            for chapter in chapters {
+                self.fmt_chapter(buf, chapter);
+                *buf += "\n";
+            }
+            buf.pop();
+            return;
+        };
+
+        let mags = chapter_mags
+            .iter()
+            .map(|(chap, mags)| {
+                format!(
+                    "{mag} in Chapter {chap}",
+                    mag = mags.trim_start_matches('(').trim_end_matches(')'),
+                    chap = chap + 1
+                )
+            })
+            .collect::<Vec<_>>();
+        match mags.as_slice() {
+            [] => unreachable!(),
+            [mag] => writeln!(buf, "Strength magnification is {mag}."),
+            [first, last] => writeln!(buf, "Strength magnifications are {first} and {last}."),
+            [init @ .., last] => writeln!(
+                buf,
+                "Strength magnifications are {init}, and {last}.",
+                init = init.join(", ")
+            ),
+        }
+        .unwrap();
+
+        let mut stages = BTreeMap::new();
+        let mut separate = vec![];
+        for stage in chapters.iter().flat_map(|chapter| &chapter.stages) {
+            let id = stage.id();
+            if is_chapter_outbreak(id) && !is_outbreak_moon(id) {
+                stages.entry(id.num()).or_insert(stage.stage_name);
+            } else {
+                separate.push(stage);
+            }
+        }
+
+        for (num, name) in stages {
+            writeln!(
+                buf,
+                "*Stage {stage}: {name}",
+                stage = num + 1,
+                name = strip_chapter_suffix(name)
+            )
+            .unwrap();
+        }
+        for stage in separate {
+            *buf += "*";
+            if is_chapter_outbreak(stage.id()) {
+                // Moon: chapter's magnification is already given
+                Self::fmt_encounter_custom(buf, stage.id(), stage.stage_name);
+            } else {
+                self.fmt_encounter(buf, stage.id(), stage.stage_name, &stage.mags);
+            }
+            *buf += "\n";
+        }
+        buf.pop();
+    }
 }
 
 const fn get_new_section(heading: &'static str, display_type: DisplayType) -> EncountersSection {
@@ -199,11 +345,11 @@ const fn get_new_section(heading: &'static str, display_type: DisplayType) -> En
 // Don't update without updating SectionRef and the first test.
 const SECTIONS: [EncountersSection; 18] = [
     get_new_section("[[Empire of Cats]]",                                    D::Custom),
-    get_new_section("[[Empire of Cats]] [[Zombie Outbreaks|Outbreaks]]",     D::Custom),
+    get_new_section("[[Empire of Cats]] [[Zombie Outbreaks|Outbreaks]]",     D::Outbreak),
     get_new_section("[[Into the Future]]",                                   D::Custom),
-    get_new_section("[[Into the Future]] [[Zombie Outbreaks|Outbreaks]]",    D::Custom),
+    get_new_section("[[Into the Future]] [[Zombie Outbreaks|Outbreaks]]",    D::Outbreak),
     get_new_section("[[Cats of the Cosmos]]",                                D::Custom),
-    get_new_section("[[Cats of the Cosmos]] [[Zombie Outbreaks|Outbreaks]]", D::Custom),
+    get_new_section("[[Cats of the Cosmos]] [[Zombie Outbreaks|Outbreaks]]", D::Outbreak),
     get_new_section("[[The Aku Realms]]",                                    D::Custom),
 
     get_new_section("[[Legend Stages#Stories of Legend|Stories of Legend]]", D::Story),
@@ -223,7 +369,7 @@ const SECTIONS: [EncountersSection; 18] = [
 
 #[repr(u8)]
 #[allow(missing_docs)]
-#[derive(Debug, Copy, Clone, PartialEq, EnumIter)]
+#[derive(Debug, Copy, Clone, PartialEq, EnumIter, Serialize)]
 /// Enum reference to a section.
 pub enum SectionRef {
     EoC,
@@ -584,4 +730,113 @@ mod tests {
             *Stage 1-3: Stage 3 (1,500% HP/2% AP)"
         );
     }
+
+    #[test]
+    fn outbreak_collapsed() {
+        let ids = [
+            StageID::from_components(T::EocOutbreak, 0, 3),
+            StageID::from_components(T::EocOutbreak, 0, 4),
+            StageID::from_components(T::EocOutbreak, 0, 47),
+            StageID::from_components(T::EocOutbreak, 1, 3),
+            StageID::from_components(T::EocOutbreak, 1, 4),
+        ];
+        let thailand = "[[Thailand (Empire of Cats)|Thailand]] (Z1)";
+        let cambodia = "[[Cambodia (Empire of Cats)|Cambodia]] (Z1)";
+        let moon = "[[Moon (Empire of Cats)|Moon]] (Z1)";
+        let chapters = vec![
+            Chapter::new(
+                Cow::Borrowed("Chapter 1"),
+                vec![
+                    Stage::new(thailand, "(200%)".into(), &ids[0]),
+                    Stage::new(cambodia, "(200%)".into(), &ids[1]),
+                    Stage::new(moon, "(200%)".into(), &ids[2]),
+                ],
+            ),
+            Chapter::new(
+                Cow::Borrowed("Chapter 2"),
+                vec![
+                    Stage::new(thailand, "(400%)".into(), &ids[3]),
+                    Stage::new(cambodia, "(400%)".into(), &ids[4]),
+                ],
+            ),
+        ];
+
+        let mut buf = String::new();
+        Ref::EocOutbreak.section().fmt_outbreak(&mut buf, chapters);
+        assert_eq!(
+            buf,
+            "Strength magnifications are 200% in Chapter 1 and 400% in Chapter 2.\n\
+            *Stage 4: [[Thailand (Empire of Cats)|Thailand]]\n\
+            *Stage 5: [[Cambodia (Empire of Cats)|Cambodia]]\n\
+            *Stage 1-48: [[Moon (Empire of Cats)|Moon]]"
+        );
+    }
+
+    #[test]
+    fn outbreak_different_stages() {
+        let ids = [
+            StageID::from_components(T::EocOutbreak, 0, 3),
+            StageID::from_components(T::EocOutbreak, 0, 47),
+            StageID::from_components(T::EocOutbreak, 1, 3),
+            StageID::from_components(T::EocOutbreak, 1, 4),
+            StageID::from_components(T::EocOutbreak, 2, 4),
+        ];
+        let thailand = "[[Thailand (Empire of Cats)|Thailand]] (Z1)";
+        let cambodia = "[[Cambodia (Empire of Cats)|Cambodia]] (Z2)";
+        let moon = "[[Moon (Empire of Cats)|Moon]] (Z1)";
+        let chapters = vec![
+            Chapter::new(
+                Cow::Borrowed("Chapter 1"),
+                vec![
+                    Stage::new(thailand, "(200%)".into(), &ids[0]),
+                    Stage::new(moon, "(200%)".into(), &ids[1]),
+                ],
+            ),
+            Chapter::new(
+                Cow::Borrowed("Chapter 2"),
+                vec![
+                    Stage::new(thailand, "(400%)".into(), &ids[2]),
+                    Stage::new(cambodia, "(400%)".into(), &ids[3]),
+                ],
+            ),
+            Chapter::new(
+                Cow::Borrowed("Chapter 3"),
+                vec![Stage::new(cambodia, "(600%)".into(), &ids[4])],
+            ),
+        ];
+
+        let mut buf = String::new();
+        Ref::EocOutbreak.section().fmt_outbreak(&mut buf, chapters);
+        assert_eq!(
+            buf,
+            "*Stage 1-4: [[Thailand (Empire of Cats)|Thailand]] (200%)\n\
+            *Stage 1-48: [[Moon (Empire of Cats)|Moon]] (200%)\n\
+            *Stage 2-4: [[Thailand (Empire of Cats)|Thailand]] (400%)\n\
+            *Stage 2-5: [[Cambodia (Empire of Cats)|Cambodia]] (400%)\n\
+            *Stage 3-5: [[Cambodia (Empire of Cats)|Cambodia]] (600%)"
+        );
+    }
+
+    #[test]
+    fn outbreak_multiple_mags() {
+        let ids = [
+            StageID::from_components(T::ItfOutbreak, 0, 0),
+            StageID::from_components(T::ItfOutbreak, 0, 1),
+        ];
+        let chapters = vec![Chapter::new(
+            Cow::Borrowed("Chapter 1"),
+            vec![
+                Stage::new("[[Stage 1]] (Z1)", "(100%)".into(), &ids[0]),
+                Stage::new("[[Stage 2]] (Z1)", "(150%)".into(), &ids[1]),
+            ],
+        )];
+
+        let mut buf = String::new();
+        Ref::ItfOutbreak.section().fmt_outbreak(&mut buf, chapters);
+        assert_eq!(
+            buf,
+            "*Stage 1-1: [[Stage 1]] (100%)\n\
+            *Stage 1-2: [[Stage 2]] (150%)"
+        );
+    }
 }
@@ -0,0 +1,296 @@
+//! Enemy abilities.
+
+use crate::{
+    game_data::{
+        cat::{
+            ability::{Ability, Surge, SurgeType, Wave, WaveType},
+            parsed::stats::form::AttackHits,
+        },
+        enemy::parsed::stats::{EnemyAbility, EnemyStats},
+    },
+    interface::scripts::cat_info::stats::abilities::{
+        misc_abilities::get_range_ability,
+        pure_abilities::get_multiple_hit_abilities,
+        util::{get_ability, get_ability_single, get_duration_repr},
+    },
+    wikitext::number_utils::{get_formatted_float, plural, time_repr},
+};
+use num_format::{Locale, ToFormattedString};
+
+fn quad_repr(quad: i32) -> String {
+    get_formatted_float(f64::from(quad) / 4.0, 2)
+}
+
+fn surge_repr(surge: &Surge) -> String {
+    let Surge {
+        stype,
+        chance,
+        spawn_quad,
+        range_quad,
+        level,
+    } = surge;
+    let name = match stype {
+        SurgeType::Surge => "[[Surge Attack]]",
+        SurgeType::MiniSurge => "[[Surge Attack#Mini-Surge|Mini-Surge]]",
+    };
+
+    let min = i32::from(*spawn_quad);
+    let position = if *range_quad == 0 {
+        format!("at {range} range", range = quad_repr(min))
+    } else {
+        format!(
+            "between {min} and {max} range",
+            max = quad_repr(min + i32::from(*range_quad)),
+            min = quad_repr(min),
+        )
+    };
+
+    format!("{chance}% chance to create a level {level} {name} {position}")
+}
+
+/// Get multihit and range abilities.
+fn get_attack_abilities(hits: &AttackHits) -> Vec<String> {
+    let mut abilities = vec![];
+
+    if !matches!(hits, AttackHits::Single(_)) {
+        let hits_repr = hits
+            .iter()
+            .map(|hit| {
+                let (fore_f, fore_s) = time_repr(hit.foreswing.into());
+                format!(
+                    "{dmg} at {fore_f}f <sup>{fore_s}s</sup>",
+                    dmg = hit.damage.to_formatted_string(&Locale::en)
+                )
+            })
+            .collect::<Vec<_>>();
+        abilities.push(format!(
+            "{{{{AbilityIcon|Multi-Hit}}}} {multi} ({hits})",
+            multi = get_ability_single("Multi-Hit"),
+            hits = hits_repr.join(", ")
+        ));
+    }
+    abilities.extend(get_range_ability(hits));
+
+    abilities
+}
+
+/// Get the ability descriptions of the enemy's shared abilities.
+fn get_shared_abilities(hits: &AttackHits, enemy_abilities: &[Ability]) -> Vec<String> {
+    let mut abilities = vec![];
+    let mut immunities = vec![];
+
+    let multab = get_multiple_hit_abilities(hits);
+    let abil = get_ability;
+    let abil2 = get_ability_single;
+
+    for ability in enemy_abilities {
+        let icon = String::from("{{AbilityIcon|") + ability.name() + "}}";
+        match ability {
+            Ability::Knockback { chance } => abilities.push(format!(
+                "{icon} {chance}% chance to {knockback} cats{multab}",
+                knockback = abil("Knockback", "knockback")
+            )),
+            Ability::Freeze { chance, duration } => abilities.push(format!(
+                "{icon} {chance}% chance to {freeze} cats for {duration}{multab}",
+                freeze = abil("Freeze", "freeze"),
+                duration = get_duration_repr(u32::from(*duration))
+            )),
+            Ability::Slow { chance, duration } => abilities.push(format!(
+                "{icon} {chance}% chance to {slow} cats for {duration}{multab}",
+                slow = abil("Slow", "slow"),
+                duration = get_duration_repr(u32::from(*duration))
+            )),
+            Ability::Crit { chance } => abilities.push(format!(
+                "{icon} {chance}% chance to perform a [[Critical Hit]]{multab}"
+            )),
+            Ability::BaseDestroyer => abilities.push(icon + " " + &abil2("Base Destroyer")),
+            Ability::Wave(Wave {
+                wtype,
+                chance,
+                level,
+            }) => {
+                let wave = match wtype {
+                    WaveType::Wave => "[[Wave Attack]]",
+                    WaveType::MiniWave => "[[Wave Attack#Mini-Wave|Mini-Wave]]",
+                };
+                abilities.push(format!(
+                    "{icon} {chance}% chance to create a level {level} {wave}{multab}"
+                ));
+            }
+            Ability::Weaken {
+                chance,
+                duration,
+                multiplier,
+            } => abilities.push(format!(
+                "{icon} {chance}% chance to {weaken} cats to {multiplier}% for {duration}{multab}",
+                weaken = abil("Weaken", "weaken"),
+                duration = get_duration_repr(u32::from(*duration))
+            )),
+            Ability::Strengthen { hp, multiplier } => abilities.push(format!(
+                "{icon} {strengthens} by {multiplier}% at {hp}% health",
+                strengthens = abil("Strengthen", "Strengthens")
+            )),
+            Ability::Survives { chance } => abilities.push(format!(
+                "{icon} {chance}% chance to {survive} a lethal strike",
+                survive = abil("Survive", "survive")
+            )),
+            Ability::WaveBlocker => abilities.push(icon + " " + &abil2("Wave Shield")),
+            Ability::SavageBlow { chance, damage } => abilities.push(format!(
+                "{icon} {chance}% chance to land a {blow} for +{damage}% damage{multab}",
+                blow = abil("Savage Blow", "savage blow"),
+            )),
+            Ability::Dodge { chance, duration } => abilities.push(format!(
+                "{icon} {chance}% chance to {dodge} attacks for {duration}",
+                dodge = abil("Dodge Attack", "dodge"),
+                duration = get_duration_repr(u32::from(*duration))
+            )),
+            Ability::Surge(surge) => {
+                abilities.push(format!("{icon} {surge}{multab}", surge = surge_repr(surge)));
+            }
+            Ability::Curse { chance, duration } => abilities.push(format!(
+                "{icon} {chance}% chance to {curse} cats for {duration}{multab}",
+                curse = abil("Curse", "curse"),
+                duration = get_duration_repr(u32::from(*duration))
+            )),
+            Ability::CounterSurge => {
+                abilities.push(icon + " [[Surge Attack#Counter-Surge|Counter-Surge]]");
+            }
+            Ability::Explosion { chance, spawn_quad } => abilities.push(format!(
+                "{icon} {chance}% chance to create an [[Explosion]] at {range} range{multab}",
+                range = quad_repr(i32::from(*spawn_quad))
+            )),
+
+            Ability::ImmuneToWave => immunities.push("Waves"),
+            Ability::ImmuneToKB => immunities.push("Knockback"),
+            Ability::ImmuneToFreeze => immunities.push("Freeze"),
+            Ability::ImmuneToSlow => immunities.push("Slow"),
+            Ability::ImmuneToWeaken => immunities.push("Weaken"),
+
+            _ => log::warn!("Ability {ability:?} is not expected on enemies."),
+        }
+    }
+
+    if !immunities.is_empty() {
+        let immunities = immunities
+            .into_iter()
+            .map(|immunity| format!("[[Special Abilities#Immune to {immunity}|{immunity}]]"))
+            .collect::<Vec<_>>();
+        abilities.push(format!("Immune to {}", immunities.join(", ")));
+    }
+
+    abilities
+}
+
+/// Get descriptions of abilities only enemies can have.
+fn get_enemy_only_abilities(enemy_abilities: &[EnemyAbility]) -> Vec<String> {
+    let amount_repr = |amount: &Option<u8>| match amount {
+        None => "unlimited times".to_string(),
+        Some(n) => format!(
+            "{n} {times}",
+            times = plural(u32::from(*n), "time", "times")
+        ),
+    };
+
+    enemy_abilities
+        .iter()
+        .map(|ability| match ability {
+            EnemyAbility::Burrow {
+                amount,
+                distance_quad,
+            } => format!(
+                "{{{{AbilityIcon|Burrow}}}} {burrow} {amount} for {range} range",
+                burrow = get_ability_single("Burrow"),
+                amount = amount_repr(amount),
+                range = quad_repr(i32::from(*distance_quad))
+            ),
+            EnemyAbility::Revive { amount, time, hp } => format!(
+                "{{{{AbilityIcon|Revive}}}} {revive} {amount} with {hp}% HP after {time}",
+                revive = get_ability("Revive", "Revives"),
+                amount = amount_repr(amount),
+                time = get_duration_repr(u32::from(*time))
+            ),
+            EnemyAbility::Warp {
+                chance,
+                duration,
+                min_quad,
+                max_quad,
+            } => format!(
+                "{{{{AbilityIcon|Warp}}}} {chance}% chance to {warp} cats {min}~{max} range \
+                for {duration}",
+                warp = get_ability("Warp", "warp"),
+                min = quad_repr(*min_quad),
+                max = quad_repr(*max_quad),
+                duration = get_duration_repr(u32::from(*duration))
+            ),
+            EnemyAbility::Barrier { hp } => format!(
+                "{{{{AbilityIcon|Barrier}}}} Has a {hp} HP [[Barrier]]",
+                hp = hp.to_formatted_string(&Locale::en)
+            ),
+            EnemyAbility::Toxic { chance, damage } => format!(
+                "{{{{AbilityIcon|Toxic}}}} {chance}% chance to deal [[Toxic]] damage \
+                equal to {damage}% of cats' max HP"
+            ),
+            EnemyAbility::Shield { hp, regen } => format!(
+                "{{{{AbilityIcon|Shield}}}} Has a {hp} HP [[Shield]] \
+                (regenerates {regen}% HP when knocked back)",
+                hp = hp.to_formatted_string(&Locale::en)
+            ),
+            EnemyAbility::DeathSurge(surge) => format!(
+                "{{{{AbilityIcon|Death Surge}}}} When killed, {surge}",
+                surge = surge_repr(surge)
+            ),
+            EnemyAbility::Starred { stars } => {
+                format!(
+                    "[[Starred Alien]] ({stars} {s})",
+                    s = plural(u32::from(*stars), "star", "stars")
+                )
+            }
+        })
+        .collect()
+}
+
+/// Get all of the enemy's ability descriptions.
+pub fn get_all_abilities(stats: &EnemyStats) -> Vec<String> {
+    let hits = &stats.attack.hits;
+    let mut abilities = get_attack_abilities(hits);
+    abilities.extend(get_shared_abilities(hits, &stats.abilities));
+    abilities.extend(get_enemy_only_abilities(&stats.enemy_abilities));
+    abilities
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_data::cat::parsed::stats::form::AttackHit;
+
+    #[test]
+    fn surge_at_position() {
+        let surge = Surge {
+            stype: SurgeType::MiniSurge,
+            chance: 100,
+            spawn_quad: 1000,
+            range_quad: 0,
+            level: 1,
+        };
+        assert_eq!(
+            surge_repr(&surge),
+            "100% chance to create a level 1 [[Surge Attack#Mini-Surge|Mini-Surge]] at 250 range"
+        );
+    }
+
+    #[test]
+    fn immunities() {
+        let hits = AttackHits::Single([AttackHit {
+            active_ability: true,
+            ..Default::default()
+        }]);
+        let abilities = [Ability::ImmuneToKB, Ability::ImmuneToFreeze];
+        assert_eq!(
+            get_shared_abilities(&hits, &abilities),
+            [
+                "Immune to [[Special Abilities#Immune to Knockback|Knockback]], \
+            [[Special Abilities#Immune to Freeze|Freeze]]"
+            ]
+        );
+    }
+}
@@ -0,0 +1,61 @@
+//! `enemy` command.
+
+use crate::{
+    game_data::enemy::parsed::enemy::Enemy,
+    interface::{
+        cli::{
+            base::BaseOptions,
+            cli_util::{CommandExec, ConfigMerge, print_json},
+            version_opt::VersionOptions,
+        },
+        config::Config,
+        scripts::enemy_info::enemy_info::get_info,
+        wiki_api::publish::{PublishOptions, print_or_publish},
+    },
+    wiki_data::enemy_data::ENEMY_DATA,
+};
+use clap::Args;
+
+#[derive(Debug, Args, PartialEq)]
+/// Enemy info options.
+pub struct EnemyInfoOptions {
+    /// Enemy name or id (Doge = 0).
+    pub name_or_id: String,
+
+    #[command(flatten)]
+    /// Wiki upload options.
+    pub publish: PublishOptions,
+    #[command(flatten)]
+    /// Global options.
+    pub base: BaseOptions,
+    #[command(flatten)]
+    /// Version options.
+    pub version: VersionOptions,
+}
+impl ConfigMerge for EnemyInfoOptions {
+    fn merge(&self, config: &mut Config) {
+        self.base.merge(config);
+        self.version.merge(config);
+    }
+}
+impl CommandExec for EnemyInfoOptions {
+    fn exec(&self, config: &Config) {
+        let id = match self.name_or_id.parse() {
+            Ok(id) => id,
+            Err(_) => match ENEMY_DATA.get_id_from_name(&self.name_or_id) {
+                Some(id) => *id,
+                None => panic!(
+                    "{e:?} is not a valid number or enemy name!",
+                    e = self.name_or_id
+                ),
+            },
+        };
+
+        if self.base.is_json() {
+            print_json(&Enemy::from_wiki_id(id, &config.version).unwrap());
+            return;
+        }
+        let info = get_info(id, config).unwrap().to_string();
+        print_or_publish(&self.publish, ENEMY_DATA.get_page(id), &info, config);
+    }
+}
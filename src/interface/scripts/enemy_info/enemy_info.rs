@@ -0,0 +1,229 @@
+//! Script for enemy info.
+
+use crate::{
+    game_data::{
+        cat::parsed::stats::form::AreaOfEffect,
+        enemy::{
+            parsed::enemy::{Enemy, EnemyDataError},
+            raw::desc::EnemyDescriptions,
+        },
+    },
+    interface::{
+        config::Config,
+        error_handler::InfallibleWrite,
+        scripts::{
+            cat_info::stats::abilities::util::get_duration_repr,
+            encounters::encounters::{encounters_section, get_stages},
+            enemy_info::abilities::get_all_abilities,
+        },
+    },
+    wiki_data::enemy_data::ENEMY_DATA,
+    wikitext::{
+        number_utils::{get_formatted_float, plural, time_repr, time_repr_i32},
+        page::Page,
+        section::Section,
+        template::{Template, TemplateParameter},
+    },
+};
+use num_format::{Locale, ToFormattedString};
+use std::{cmp::max, fmt::Write};
+
+fn intro(enemy: &Enemy) -> Section {
+    let name = &ENEMY_DATA.get_names(enemy.id).name;
+    Section::blank(format!("'''{name}''' is an enemy in ''The Battle Cats''."))
+}
+
+/// "Description" template.
+fn get_descs(enemy: &Enemy, config: &Config) -> Template {
+    type P = TemplateParameter;
+
+    let mut descs = Template::new(
+        "Description",
+        vec![
+            P::new("Mode", "Enemy"),
+            P::new("Number", enemy.id.to_string()),
+        ],
+    );
+
+    let versions = [
+        (config.version.en(), ""),
+        (config.version.jp(), " (JP)"),
+        (config.version.tw(), " (TW)"),
+        (config.version.kr(), " (KR)"),
+    ];
+    for (version, suffix) in versions {
+        let lang_descs = version.get_cached_file::<EnemyDescriptions>();
+        if let Some(desc) = lang_descs.get_desc(enemy.id) {
+            descs.push_params([
+                P::new(format!("Name{suffix}"), desc.name().to_string()),
+                P::new(format!("Desc{suffix}"), desc.lines()),
+            ]);
+        }
+    }
+
+    descs
+}
+
+/// Attack frequency of the enemy. [`None`] if enemy can't attack.
+fn get_frequency(enemy: &Enemy) -> Option<u16> {
+    let attack = &enemy.stats.attack;
+    let attack_length = attack.hits.attack_length();
+    let anim_length = enemy.anim.attack.length();
+
+    if anim_length <= attack_length {
+        log::info!(
+            "Attack animation length mismatch: {anim_length} <= {attack_length}. Enemy cannot attack.",
+        );
+        return None;
+    }
+
+    Some(max(anim_length, attack_length + attack.cooldown))
+}
+
+/// "Enemy Stats" template.
+fn stats_template(enemy: &Enemy) -> Template {
+    type P = TemplateParameter;
+    let stats = &enemy.stats;
+    let frequency = get_frequency(enemy);
+
+    let hp = format!("{hp} HP", hp = stats.hp.to_formatted_string(&Locale::en));
+    let atk = {
+        let dmg = stats.attack.hits.total_damage();
+        let mut buf = format!("{ap} damage", ap = dmg.to_formatted_string(&Locale::en));
+        if let Some(frequency) = frequency {
+            let dps = f64::from(dmg) / f64::from(frequency) * 30.0;
+            write!(buf, "<br>({dps} DPS)", dps = get_formatted_float(dps, 2)).infallible_write();
+        }
+        buf
+    };
+    let attack_frequency = match frequency {
+        Some(frequency) => get_duration_repr(u32::from(frequency)),
+        None => "Cannot attack".to_string(),
+    };
+    let animation = {
+        let attack_length = stats.attack.hits.attack_length();
+        let (fore_f, fore_s) = time_repr(u32::from(stats.attack.hits.foreswing()));
+        let backswing = i32::from(enemy.anim.attack.length()) - i32::from(attack_length);
+        let (back_f, back_s) = time_repr_i32(backswing);
+        format!("{fore_f}f <sup>{fore_s}s</sup><br>({back_f}f <sup>{back_s}s</sup> backswing)")
+    };
+    let attack_type = match stats.attack.aoe {
+        AreaOfEffect::SingleAttack => "Single",
+        AreaOfEffect::AreaAttack => "Area",
+    };
+    let abilities = {
+        let abilities = get_all_abilities(stats);
+        if abilities.is_empty() {
+            "-".to_string()
+        } else {
+            abilities.join("<br>\n")
+        }
+    };
+    let traits = {
+        let traits = stats
+            .traits
+            .iter()
+            .map(|t| format!("[[:Category:{t} Enemies|{t}]]"))
+            .chain(
+                stats
+                    .subtypes
+                    .iter()
+                    .map(|t| format!("[[:Category:{t} Enemies|{t}]]")),
+            )
+            .collect::<Vec<_>>();
+        if traits.is_empty() {
+            "-".to_string()
+        } else {
+            traits.join(", ")
+        }
+    };
+
+    Template::named("Enemy Stats").add_params([
+        P::new("Health", hp),
+        P::new("Attack Power", atk),
+        P::new(
+            "Attack Range",
+            stats.attack.standing_range.to_formatted_string(&Locale::en),
+        ),
+        P::new("Attack Frequency", attack_frequency),
+        P::new(
+            "Movement Speed",
+            stats.speed.to_formatted_string(&Locale::en),
+        ),
+        P::new(
+            "Knockback",
+            format!(
+                "{kb} {times}",
+                kb = stats.kb.to_formatted_string(&Locale::en),
+                times = plural(u32::from(stats.kb), "time", "times")
+            ),
+        ),
+        P::new("Attack Animation", animation),
+        P::new("Attack Type", attack_type),
+        P::new("Special Ability", abilities),
+        P::new(
+            "Money Drop",
+            format!(
+                "{money}¢",
+                money = stats.money_drop.to_formatted_string(&Locale::en)
+            ),
+        ),
+        P::new("Type", traits),
+    ])
+}
+
+fn reference(id: u32) -> Section {
+    let id = id + 2;
+    let reference = format!("*https://battlecats-db.com/enemy/{id:03}.html");
+    Section::h2("Reference", reference)
+}
+
+/// Get enemy info.
+pub fn get_info(wiki_id: u32, config: &Config) -> Result<Page, EnemyDataError> {
+    let enemy = Enemy::from_wiki_id(wiki_id, &config.version)?;
+    let all_stages = get_stages(config.version.current_version()).collect::<Vec<_>>();
+
+    let mut page = Page::blank();
+
+    page.push(intro(&enemy));
+    page.push(Section::h2(
+        "Description",
+        get_descs(&enemy, config).to_string(),
+    ));
+    page.push(Section::h2("Stats", stats_template(&enemy).to_string()));
+    page.push(encounters_section(enemy.id, &all_stages));
+    page.push(reference(enemy.id));
+
+    Ok(page)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::TEST_CONFIG;
+
+    #[test]
+    fn reference_id() {
+        assert_eq!(
+            reference(0).to_string(),
+            "==Reference==\n*https://battlecats-db.com/enemy/002.html"
+        );
+    }
+
+    #[test]
+    fn doge_stats() {
+        let doge = Enemy::from_wiki_id(0, &TEST_CONFIG.version).unwrap();
+        let (_, params) = stats_template(&doge).deconstruct();
+        let health = params.iter().find(|p| p.key == "Health").unwrap();
+        assert_eq!(health.value, "90 HP");
+    }
+
+    #[test]
+    fn doge_descs() {
+        let doge = Enemy::from_wiki_id(0, &TEST_CONFIG.version).unwrap();
+        let (_, params) = get_descs(&doge, &TEST_CONFIG).deconstruct();
+        let name = |key: &str| &params.iter().find(|p| p.key == key).unwrap().value;
+        assert_eq!(name("Name"), "Doge");
+        assert_eq!(name("Name (JP)"), "わんこ");
+    }
+}
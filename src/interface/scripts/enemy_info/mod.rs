@@ -0,0 +1,5 @@
+//! Get info about an enemy.
+
+pub mod abilities;
+pub mod enemy_cli;
+pub mod enemy_info;
@@ -0,0 +1,389 @@
+//! Get information about gacha banners.
+
+use crate::{
+    game_data::{
+        cat::{parsed::unitbuy::Rarity, raw::unitbuy::UnitBuyContainer},
+        gacha::{
+            gatya_event::{GatyaDate, GatyaEvent, GatyaEventGacha, RATE_RARITIES},
+            gatya_option::{GatyaOption, GatyaOptions},
+            gatya_set::GatyaSets,
+        },
+        version::Version,
+    },
+    interface::error_handler::InfallibleWrite,
+    wiki_data::cat_data::CAT_DATA,
+    wikitext::{number_utils::get_formatted_float, page::Page, section::Section},
+};
+use serde::Serialize;
+use std::fmt::Write;
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+/// Cat that can be obtained from a banner.
+pub struct BannerCat {
+    /// Cat's wiki id.
+    pub id: u32,
+    /// Cat's rarity, if it exists in `unitbuy.csv`.
+    pub rarity: Option<Rarity>,
+    /// Does the cat appear more than once in the set.
+    pub rate_up: bool,
+}
+
+#[derive(Debug, Serialize)]
+/// Gacha banner that is available during an event.
+pub struct Banner {
+    /// When the banner starts.
+    pub start: GatyaDate,
+    /// When the banner ends.
+    pub end: GatyaDate,
+    /// Banner's gacha data.
+    pub gacha: GatyaEventGacha,
+    /// Display options of the banner's gacha set.
+    pub option: Option<GatyaOption>,
+    /// Cats in the banner.
+    pub cats: Vec<BannerCat>,
+}
+impl Banner {
+    /// Name of the banner.
+    pub fn name(&self) -> String {
+        if self.gacha.message.is_empty() {
+            format!("Gacha {id}", id = self.gacha.id)
+        } else {
+            self.gacha.message.clone()
+        }
+    }
+
+    /// Id of the series the banner's gacha set belongs to, if it is part of
+    /// one.
+    pub fn series_id(&self) -> Option<u32> {
+        let option = self.option.as_ref()?;
+        u32::try_from(option.series_id).ok()
+    }
+
+    /// Does the banner contain the cat.
+    pub fn get_cat(&self, id: u32) -> Option<&BannerCat> {
+        self.cats.iter().find(|cat| cat.id == id)
+    }
+}
+
+/// Get every unique cat in the gacha set.
+fn banner_cats(set: &[u32], version: &Version) -> Vec<BannerCat> {
+    let unitbuy = version.get_cached_file::<UnitBuyContainer>();
+
+    let mut cats: Vec<BannerCat> = vec![];
+    for &id in set {
+        if let Some(cat) = cats.iter_mut().find(|cat| cat.id == id) {
+            cat.rate_up = true;
+            continue;
+        }
+        let rarity = unitbuy
+            .get_unit(id)
+            .and_then(|unit| Rarity::from_repr(unit.rarity));
+        cats.push(BannerCat {
+            id,
+            rarity,
+            rate_up: false,
+        });
+    }
+
+    cats
+}
+
+/// Get every Rare Cat Capsule banner in the gacha events. Banners that are
+/// hidden in game are skipped.
+pub fn get_banners(events: &[GatyaEvent], version: &Version) -> Vec<Banner> {
+    let sets = version.get_cached_file::<GatyaSets>();
+    let options = version.get_cached_file::<GatyaOptions>();
+
+    let mut banners = vec![];
+    for event in events.iter().filter(|event| event.is_rare_capsule()) {
+        for gacha in &event.gachas {
+            let option = options.get_option(gacha.id);
+            if option.is_some_and(|option| !option.banner_on) {
+                continue;
+            }
+
+            let cats = match sets.get_set(gacha.id) {
+                Some(set) => banner_cats(set, version),
+                None => {
+                    log::warn!("Gacha set {id} not found.", id = gacha.id);
+                    vec![]
+                }
+            };
+
+            banners.push(Banner {
+                start: event.start,
+                end: event.end,
+                gacha: gacha.clone(),
+                option: option.cloned(),
+                cats,
+            });
+        }
+    }
+
+    banners
+}
+
+/// Link to the cat's page.
+fn cat_link(id: u32) -> String {
+    match CAT_DATA.try_get_cat(id as usize) {
+        Some(_) => CAT_DATA.get_cat_link(id),
+        None => format!("Cat {id}"),
+    }
+}
+
+/// One-line summary of every banner.
+pub fn banner_list(banners: &[Banner]) -> String {
+    let mut buf = String::new();
+    for banner in banners {
+        write!(
+            buf,
+            "{id:03} {name}: {start} to {end}",
+            id = banner.gacha.id,
+            name = banner.name(),
+            start = banner.start,
+            end = banner.end,
+        )
+        .infallible_write();
+        if banner.gacha.step_up {
+            buf.write_str(" (step-up)").infallible_write();
+        }
+        if banner.gacha.is_guaranteed() {
+            buf.write_str(" (guaranteed)").infallible_write();
+        }
+        if let Some(series) = banner.series_id() {
+            write!(buf, " (series {series})").infallible_write();
+        }
+        buf.write_char('\n').infallible_write();
+    }
+
+    buf
+}
+
+/// Table of the chance and guaranteed status of each rarity.
+fn rates_table(gacha: &GatyaEventGacha) -> String {
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Rarity\n\
+        ! scope=\"col\" | Chance\n\
+        ! scope=\"col\" | Guaranteed",
+    );
+    for rate in gacha.rates.iter().filter(|rate| rate.rate > 0) {
+        write!(
+            buf,
+            "\n|-\n\
+            | {rarity}\n\
+            | {chance}%\n\
+            | {guaranteed}",
+            rarity = rate.rarity.category(),
+            chance = get_formatted_float(f64::from(rate.rate) / 100.0, 2),
+            guaranteed = if rate.guaranteed { "Yes" } else { "No" },
+        )
+        .infallible_write();
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    buf
+}
+
+/// List of cats of a single rarity.
+fn cat_list<'a>(cats: impl Iterator<Item = &'a BannerCat>) -> String {
+    cats.map(|cat| {
+        let rate_up = if cat.rate_up { " (Rate Up)" } else { "" };
+        format!("*{link}{rate_up}", link = cat_link(cat.id))
+    })
+    .collect::<Vec<_>>()
+    .join("\n")
+}
+
+/// Get the wikitext of the banner's page.
+pub fn banner_page(banner: &Banner) -> String {
+    let mut page = Page::blank();
+
+    let mut intro = format!(
+        "'''{name}''' is a [[Rare Cat Capsule]] event that was available from \
+        {start} to {end}.",
+        name = banner.name(),
+        start = banner.start,
+        end = banner.end,
+    );
+    if banner.gacha.step_up {
+        intro += " It is a [[Step-Up]] gacha, so the price and rewards change \
+            with each roll.";
+    }
+    page.push(Section::blank(intro));
+    page.push(Section::h2("Rates", rates_table(&banner.gacha)));
+
+    let mut cats = Page::blank();
+    for rarity in RATE_RARITIES.iter().rev() {
+        let mut with_rarity = banner
+            .cats
+            .iter()
+            .filter(|cat| cat.rarity.as_ref() == Some(rarity))
+            .peekable();
+        if with_rarity.peek().is_some() {
+            cats.push(Section::h3(format!("{rarity} Cats"), cat_list(with_rarity)));
+        }
+    }
+    let mut other = banner
+        .cats
+        .iter()
+        .filter(|cat| {
+            cat.rarity
+                .is_none_or(|rarity| !RATE_RARITIES.contains(&rarity))
+        })
+        .peekable();
+    if other.peek().is_some() {
+        cats.push(Section::h3("Other", cat_list(other)));
+    }
+    page.push(Section::h2("Cats", cats.to_string()));
+
+    page.to_string()
+}
+
+/// Get the "Obtained from" list of the cat's page.
+pub fn obtained_from(cat_id: u32, banners: &[Banner]) -> Option<String> {
+    let lines = banners
+        .iter()
+        .filter_map(|banner| {
+            let cat = banner.get_cat(cat_id)?;
+            let rate_up = if cat.rate_up { " (Rate Up)" } else { "" };
+            Some(format!(
+                "*[[{name}]] ({start} to {end}){rate_up}",
+                name = banner.name(),
+                start = banner.start,
+                end = banner.end,
+            ))
+        })
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TEST_CONFIG,
+        game_data::gacha::gatya_event::{GatyaRate, RARE_GACHA_TYPE},
+    };
+
+    fn banner() -> Banner {
+        let rates = RATE_RARITIES.map(|rarity| GatyaRate {
+            rarity,
+            rate: match rarity {
+                Rarity::Rare => 7000,
+                Rarity::SuperRare => 2500,
+                Rarity::UberRare => 500,
+                _ => 0,
+            },
+            guaranteed: rarity == Rarity::UberRare,
+        });
+        Banner {
+            start: GatyaDate {
+                date: 20240101,
+                time: 1100,
+            },
+            end: GatyaDate {
+                date: 20240108,
+                time: 1059,
+            },
+            gacha: GatyaEventGacha {
+                id: 602,
+                step_up: false,
+                rates,
+                message: "New Year's Gacha".to_string(),
+            },
+            option: None,
+            cats: vec![
+                BannerCat {
+                    id: 9999,
+                    rarity: Some(Rarity::UberRare),
+                    rate_up: true,
+                },
+                BannerCat {
+                    id: 9998,
+                    rarity: None,
+                    rate_up: false,
+                },
+            ],
+        }
+    }
+
+    #[test]
+    fn only_rare_capsules() {
+        let mut event = GatyaEvent {
+            start: banner().start,
+            end: banner().end,
+            min_version: 0,
+            max_version: 999_999,
+            gacha_type: 0,
+            gachas: vec![banner().gacha],
+        };
+        let version = TEST_CONFIG.version.current_version();
+        assert!(get_banners(std::slice::from_ref(&event), version).is_empty());
+
+        event.gacha_type = RARE_GACHA_TYPE;
+        assert_eq!(get_banners(&[event], version).len(), 1);
+    }
+
+    #[test]
+    fn rates() {
+        let table = rates_table(&banner().gacha);
+        assert!(!table.contains("Normal Cat"));
+        assert!(table.contains("\n|-\n| [[:Category:Rare Cats|Rare Cat]]\n| 70%\n| No"));
+        assert!(table.contains("\n|-\n| [[:Category:Uber Rare Cats|Uber Rare Cat]]\n| 5%\n| Yes"));
+    }
+
+    #[test]
+    fn page() {
+        let page = banner_page(&banner());
+        assert!(page.starts_with(
+            "'''New Year's Gacha''' is a [[Rare Cat Capsule]] event that was available \
+            from January 1, 2024 to January 8, 2024."
+        ));
+        assert!(page.contains("=== Uber Rare Cats ===\n*Cat 9999 (Rate Up)"));
+        assert!(page.contains("=== Other ===\n*Cat 9998"));
+    }
+
+    #[test]
+    fn list() {
+        let mut banner = banner();
+        assert_eq!(
+            banner_list(std::slice::from_ref(&banner)),
+            "602 New Year's Gacha: January 1, 2024 to January 8, 2024 (guaranteed)\n"
+        );
+
+        banner.option = Some(GatyaOption {
+            id: 602,
+            banner_on: true,
+            series_id: 5,
+        });
+        assert_eq!(banner.series_id(), Some(5));
+        assert!(banner_list(&[banner]).ends_with(" (guaranteed) (series 5)\n"));
+    }
+
+    #[test]
+    fn no_series() {
+        let mut banner = banner();
+        banner.option = Some(GatyaOption {
+            id: 602,
+            banner_on: true,
+            series_id: -1,
+        });
+        assert_eq!(banner.series_id(), None);
+    }
+
+    #[test]
+    fn obtained() {
+        let banners = [banner()];
+        assert_eq!(
+            obtained_from(9999, &banners).unwrap(),
+            "*[[New Year's Gacha]] (January 1, 2024 to January 8, 2024) (Rate Up)"
+        );
+        assert_eq!(obtained_from(0, &banners), None);
+    }
+}
@@ -0,0 +1,110 @@
+//! `gacha` command.
+
+use super::gacha::{banner_list, banner_page, get_banners, obtained_from};
+use crate::{
+    game_data::gacha::gatya_event::read_gatya_events,
+    interface::{
+        cli::{
+            base::BaseOptions,
+            cli_util::{CommandExec, ConfigMerge, print_json},
+            version_opt::VersionOptions,
+        },
+        config::Config,
+    },
+    wiki_data::cat_data::CAT_DATA,
+};
+use clap::Args;
+use std::path::PathBuf;
+
+#[derive(Debug, Args, PartialEq)]
+/// Gacha options.
+pub struct GachaOptions {
+    /// Gacha event data file (`gatya.tsv`).
+    pub event_file: PathBuf,
+
+    #[arg(long)]
+    /// Get the page of every banner with this gacha set id.
+    pub id: Option<u32>,
+
+    #[arg(long)]
+    /// Get the "Obtained from" list of this cat (name or id) instead.
+    pub cat: Option<String>,
+
+    #[command(flatten)]
+    /// Global options.
+    pub base: BaseOptions,
+    #[command(flatten)]
+    /// Version options.
+    pub version: VersionOptions,
+}
+impl ConfigMerge for GachaOptions {
+    fn merge(&self, config: &mut Config) {
+        self.base.merge(config);
+        self.version.merge(config);
+    }
+}
+impl CommandExec for GachaOptions {
+    fn exec(&self, config: &Config) {
+        let events = read_gatya_events(&self.event_file).unwrap_or_else(|e| {
+            panic!(
+                "Couldn't read gacha event data from {file:?}: {e}",
+                file = self.event_file
+            )
+        });
+        let mut banners = get_banners(&events, config.version.current_version());
+        if let Some(id) = self.id {
+            banners.retain(|banner| banner.gacha.id == id);
+        }
+
+        if let Some(cat) = &self.cat {
+            let id = match cat.parse() {
+                Ok(id) => id,
+                Err(_) => CAT_DATA
+                    .get_id_from_name(cat)
+                    .unwrap_or_else(|| panic!("{cat:?} is not a valid number or cat name!")),
+            };
+            banners.retain(|banner| banner.get_cat(id).is_some());
+
+            if self.base.is_json() {
+                print_json(&banners);
+            } else {
+                println!("{}", obtained_from(id, &banners).unwrap_or_default());
+            }
+            return;
+        }
+
+        if self.base.is_json() {
+            print_json(&banners);
+        } else if self.id.is_some() {
+            let pages = banners.iter().map(banner_page).collect::<Vec<_>>();
+            println!("{}", pages.join("\n\n"));
+        } else {
+            print!("{}", banner_list(&banners));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::cli::commands::{Cli, Command};
+    use clap::Parser;
+
+    #[test]
+    fn gacha_cat() {
+        const ARGS: [&str; 5] = ["run_program", "gacha", "gatya.tsv", "--cat", "Bahamut Cat"];
+        let cli = Cli::parse_from(ARGS.iter());
+        assert_eq!(
+            cli,
+            Cli {
+                command: Command::Gacha(GachaOptions {
+                    event_file: "gatya.tsv".into(),
+                    id: None,
+                    cat: Some("Bahamut Cat".to_string()),
+                    base: Default::default(),
+                    version: Default::default(),
+                }),
+            }
+        );
+    }
+}
@@ -0,0 +1,4 @@
+//! Get info about gacha banners.
+
+pub mod gacha;
+pub mod gacha_cli;
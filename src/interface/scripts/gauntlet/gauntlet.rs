@@ -20,6 +20,7 @@ use crate::{
             stage::Stage,
             stage_enemy::{MS_SIGN, Magnification, StageEnemy},
         },
+        version::Version,
     },
     interface::{config::Config, error_handler::InfallibleWrite},
     wiki_data::enemy_data::ENEMY_DATA,
@@ -200,7 +201,7 @@ fn write_single_mag(buf: &mut String, mag: &Magnification) {
 }
 
 /// Write a single data row to the table.
-fn write_table_row(line_buf: &mut String, enemies_by_id: &[u32], stage: &Stage) {
+fn write_table_row(line_buf: &mut String, enemies_by_id: &[u32], stage: &Stage, version: &Version) {
     for mag_line in enemy_mag_lines(enemies_by_id, &stage.enemies) {
         line_buf.write_str("|").infallible_write();
 
@@ -214,7 +215,7 @@ fn write_table_row(line_buf: &mut String, enemies_by_id: &[u32], stage: &Stage)
     }
     // |x%, y% HP/z% AP etc.
 
-    let rewards = match treasure(stage) {
+    let rewards = match treasure(stage, version) {
         Some(t) => {
             let mut c = t.value.as_ref();
             c = c.strip_prefix("- ").unwrap();
@@ -228,7 +229,7 @@ fn write_table_row(line_buf: &mut String, enemies_by_id: &[u32], stage: &Stage)
         line_buf,
         "|{base_hp}\n|{energy}\n|{rewards}\n|{xp}",
         base_hp = base_hp(stage)[0].value,
-        energy = energy(stage).unwrap().value,
+        energy = energy(stage, version).unwrap().value,
         rewards = rewards,
         xp = xp(stage).unwrap().value,
     )
@@ -236,7 +237,7 @@ fn write_table_row(line_buf: &mut String, enemies_by_id: &[u32], stage: &Stage)
 }
 
 /// Get gauntlet scale table.
-fn get_table(stages: &[Stage], ranges: &[StageRange]) -> String {
+fn get_table(stages: &[Stage], ranges: &[StageRange], version: &Version) -> String {
     let stage1 = &stages[ranges[0].min as usize];
     let enemies_by_id = get_enemies_by_id(stage1);
 
@@ -288,7 +289,7 @@ fn get_table(stages: &[Stage], ranges: &[StageRange]) -> String {
             write!(table, "|-\n! scope=\"row\" |{}\n", i + 1).unwrap();
             // new row, add stage number marker
             let stage = &stages[i as usize];
-            write_table_row(&mut table, &enemies_by_id, stage);
+            write_table_row(&mut table, &enemies_by_id, stage, version);
             // other cols in line
             table.write_str("\n").unwrap();
         }
@@ -299,7 +300,7 @@ fn get_table(stages: &[Stage], ranges: &[StageRange]) -> String {
 }
 
 /// Get all valid stages in map.
-fn get_stages(map_id: &MapID, config: &Config) -> Vec<Stage> {
+pub fn get_stages(map_id: &MapID, config: &Config) -> Vec<Stage> {
     let mut stages = vec![];
     for i in 0..100 {
         let id = StageID::from_map(map_id.clone(), i);
@@ -346,7 +347,7 @@ fn map_tabber(map_id: &MapID, config: &Config) -> Tabber {
             .add_params(schap.clone())
             .add_params(max_clears(tab_stage0));
 
-        let table = get_table(&stages, &ranges);
+        let table = get_table(&stages, &ranges, config.version.current_version());
 
         let cont = tab.1;
         let sections = [
@@ -5,12 +5,15 @@ use crate::{
     interface::{
         cli::{
             base::BaseOptions,
-            cli_util::{CommandExec, ConfigMerge, input},
+            cli_util::{CommandExec, ConfigMerge, input, print_json},
             version_opt::VersionOptions,
         },
         config::Config,
-        scripts::gauntlet::gauntlet::map_gauntlet,
+        scripts::gauntlet::gauntlet::{get_stages, map_gauntlet},
+        wiki_api::publish::{PublishOptions, print_or_publish},
     },
+    wiki_data::stage_wiki_data::STAGE_WIKI_DATA,
+    wikitext::text_utils::extract_link,
 };
 use clap::Args;
 
@@ -20,6 +23,9 @@ pub struct GauntletOptions {
     /// Gauntlet map selector.
     pub selector: Vec<String>,
 
+    #[command(flatten)]
+    /// Wiki upload options.
+    pub publish: PublishOptions,
     #[command(flatten)]
     /// Global options.
     pub base: BaseOptions,
@@ -42,7 +48,14 @@ impl CommandExec for GauntletOptions {
         };
 
         let gauntlet_id = parse_general_map_id(&selector).unwrap();
+        if self.base.is_json() {
+            print_json(&get_stages(&gauntlet_id, config));
+            return;
+        }
         let info = map_gauntlet(&gauntlet_id, config);
-        println!("{info}");
+        let data = STAGE_WIKI_DATA
+            .stage_map(&gauntlet_id)
+            .unwrap_or_else(|| panic!("Couldn't find map name: {gauntlet_id}"));
+        print_or_publish(&self.publish, extract_link(&data.name), &info, config);
     }
 }
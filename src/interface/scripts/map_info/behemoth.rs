@@ -0,0 +1,130 @@
+//! Behemoth Culling map info.
+
+use super::{
+    common::{continuations, crown_table, map_stages, materials, stage_table},
+    legend::get_map_wiki_data,
+    map_info::db_reference,
+};
+use crate::{
+    game_data::{map::parsed::map::GameMap, stage::parsed::stage::Stage, version::Version},
+    interface::{
+        config::Config, error_handler::InfallibleWrite, scripts::stage_info::treasure::treasure,
+    },
+    wiki_data::stage_wiki_data::MapWikiData,
+    wikitext::{
+        page::Page,
+        section::Section,
+        text_utils::{extract_link, extract_name},
+    },
+};
+use std::fmt::Write;
+
+/// Introduction sentence.
+fn intro(map_wiki_data: &MapWikiData, amt_stages: u32) -> Section {
+    Section::blank(format!(
+        "'''{name}''' (?, ''?'', '''?''') is a [[Behemoth Culling]] map \
+        containing {amt_stages} stages.",
+        name = extract_name(&map_wiki_data.name)
+    ))
+}
+
+/// Templates and categories at the bottom of the page.
+const FOOTER: &str = "{{SpecialStages List}}\n\
+    [[Category:Event Stages]]\n\
+    [[Category:Behemoth Culling]]";
+
+/// Table of each stage's treasure drops. Returns [`None`] if no stage has any
+/// drops.
+fn treasure_table(
+    stages: &[Stage],
+    map_wiki_data: &MapWikiData,
+    version: &Version,
+) -> Option<String> {
+    let drops = stages
+        .iter()
+        .map(|stage| treasure(stage, version))
+        .collect::<Vec<_>>();
+    if drops.iter().all(Option::is_none) {
+        return None;
+    }
+
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Stage\n\
+        ! scope=\"col\" | Drops",
+    );
+    for (stage, drop) in stages.iter().zip(drops) {
+        let num = stage.id.num();
+        let name = match map_wiki_data.get(num) {
+            Some(data) => format!(
+                "[[{link}|Stage {n}]]",
+                link = extract_link(&data.name),
+                n = num + 1
+            ),
+            None => format!("Stage {n}", n = num + 1),
+        };
+        match drop {
+            Some(param) => write!(buf, "\n|-\n! scope=\"row\" | {name}\n| {}", param.value),
+            None => write!(buf, "\n|-\n! scope=\"row\" | {name}\n| -"),
+        }
+        .infallible_write();
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    Some(buf)
+}
+
+/// Get Behemoth Culling map info.
+pub fn get_behemoth_map(map: &GameMap, config: &Config) -> String {
+    let version = config.version.current_version();
+    let map_wiki_data = get_map_wiki_data(&map.id);
+    let stages = map_stages(map, version);
+
+    let mut page = Page::blank();
+
+    page.push(intro(map_wiki_data, stages.len() as u32));
+    if let Some(table) = map.crown_data.as_ref().and_then(crown_table) {
+        page.push(Section::h2("Difficulty", table));
+    }
+    page.push(Section::h2(
+        "List of Stages",
+        stage_table(map, map_wiki_data, version),
+    ));
+    if let Some(table) = treasure_table(&stages, map_wiki_data, version) {
+        page.push(Section::h2("Treasure", table));
+    }
+    if let Some(materials) = materials(map, version) {
+        page.push(Section::blank(materials));
+    }
+    if let Some(continuations) = continuations(&stages, map_wiki_data) {
+        page.push(Section::h2("Continuation Stages", continuations));
+    }
+    page.push(Section::h2(
+        "Reference",
+        "*".to_string() + &db_reference(&map.id),
+    ));
+    page.push(Section::blank(FOOTER));
+
+    page.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TEST_CONFIG,
+        game_data::meta::stage::{map_id::MapID, variant::StageVariantID as T},
+    };
+
+    #[test]
+    fn first_map_treasure() {
+        let version = TEST_CONFIG.version.current_version();
+        let map = GameMap::from_id(MapID::from_components(T::Behemoth, 0), version);
+        let map_wiki_data = get_map_wiki_data(&map.id);
+        let stage = map.get_stage(0, version).unwrap();
+
+        let table = treasure_table(&[stage], map_wiki_data, version).unwrap();
+        assert!(table.contains("|Stage 1]]\n| "));
+        assert!(table.ends_with("\n|}"));
+    }
+}
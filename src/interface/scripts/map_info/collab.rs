@@ -0,0 +1,193 @@
+//! Collaboration event map info.
+
+use super::{
+    common::{crown_table, map_img, map_restrictions, map_stages, materials, nav_item_opt},
+    event::overview,
+    legend::get_map_wiki_data,
+    map_info::db_reference,
+};
+use crate::{
+    game_data::{
+        map::parsed::map::GameMap, meta::stage::variant::StageVariantID as T,
+        stage::parsed::stage::Stage, version::Version,
+    },
+    interface::{
+        config::Config,
+        error_handler::InfallibleWrite,
+        scripts::{
+            map_info::common::stage_table,
+            stage_info::{
+                misc_information::{collab_event_name, collab_templates},
+                treasure::is_unit_drop,
+            },
+        },
+    },
+    wiki_data::{
+        rewards::TREASURE_DATA,
+        stage_wiki_data::{MapWikiData, STAGE_WIKI_DATA},
+    },
+    wikitext::{page::Page, section::Section, text_utils::extract_name},
+};
+use std::fmt::Write;
+
+/// Introduction sentences.
+fn intro(map: &GameMap, map_wiki_data: &MapWikiData, event_name: &str) -> String {
+    let kind = match map.id.variant() {
+        T::CollabGauntlet => "a [[Gauntlet]]",
+        _ => "a map",
+    };
+
+    let mut buf = format!(
+        "'''{name}''' (?, ''?'', '''?''') is {kind} in the [[{event_name}]].",
+        name = extract_name(&map_wiki_data.name),
+    );
+    if let Some(crown_data) = &map.crown_data {
+        write!(
+            buf,
+            " It is available up to {{{{{diff}c}}}} difficulty.",
+            diff = crown_data.max_difficulty
+        )
+        .infallible_write();
+    }
+
+    buf
+}
+
+/// Cats that can be obtained as a reward from any of the stages.
+fn reward_cats(stages: &[Stage], version: &Version) -> Option<String> {
+    let mut ids: Vec<u32> = vec![];
+    for rewards in stages.iter().filter_map(|stage| stage.rewards.as_ref()) {
+        let treasure = rewards.treasure_drop.iter().map(|t| t.item_id);
+        let score = rewards.score_rewards.iter().map(|s| s.item_id);
+        for id in treasure.chain(score) {
+            if is_unit_drop(id) && !ids.contains(&id) {
+                ids.push(id);
+            }
+        }
+    }
+
+    if ids.is_empty() {
+        return None;
+    }
+
+    let cats = ids
+        .into_iter()
+        .map(|id| {
+            format!(
+                "*{name}",
+                name = TREASURE_DATA.get_treasure_name(id, version)
+            )
+        })
+        .collect::<Vec<_>>();
+    Some(cats.join("\n"))
+}
+
+/// Navigation to the previous and next maps of the same collab.
+pub fn nav(map: &GameMap, event_name: &str) -> String {
+    let type_data = STAGE_WIKI_DATA.stage_type(map.id.variant()).unwrap();
+    let same_collab = |num: u32| {
+        type_data
+            .get(num)
+            .filter(|data| collab_event_name(&data.name) == Some(event_name))
+            .map(|data| extract_name(&data.name))
+    };
+
+    let left = match map.id.num() {
+        0 => None,
+        n => same_collab(n - 1),
+    };
+    let right = same_collab(map.id.num() + 1);
+
+    nav_item_opt(&format!("[[{event_name}]]"), left, right)
+}
+
+/// Get the name of the map's collaboration event page.
+pub fn get_event_name(map_wiki_data: &MapWikiData) -> &str {
+    collab_event_name(&map_wiki_data.name).unwrap_or_else(|| {
+        panic!(
+            "Couldn't find collab event name in map name: {}",
+            map_wiki_data.name
+        )
+    })
+}
+
+/// Templates and categories at the bottom of a collab or collab gauntlet
+/// page.
+pub fn footer(variant: T, event_name: &str) -> String {
+    let mut buf = collab_templates(event_name) + "\n[[Category:Collaboration Stages]]";
+    if variant == T::CollabGauntlet {
+        buf += "\n[[Category:Gauntlets]]";
+    }
+    buf
+}
+
+/// Get collab map info. Collab gauntlets use the gauntlet preset instead.
+pub fn get_collab_map(map: &GameMap, config: &Config) -> String {
+    let version = config.version.current_version();
+    let map_wiki_data = get_map_wiki_data(&map.id);
+    let event_name = get_event_name(map_wiki_data);
+    let stages = map_stages(map, version);
+
+    let mut page = Page::blank();
+
+    page.push(Section::blank(
+        map_img(map) + "\n" + &intro(map, map_wiki_data, event_name),
+    ));
+    page.push(Section::h2(
+        "Overview",
+        overview(map, map_wiki_data, stages.len() as u32),
+    ));
+    if let Some(table) = map.crown_data.as_ref().and_then(crown_table) {
+        page.push(Section::h2("Difficulty", table));
+    }
+    if let Some(restrictions) = map_restrictions(&stages, "Stage") {
+        page.push(Section::h2("Restrictions", restrictions));
+    }
+    page.push(Section::h2(
+        "List of Stages",
+        stage_table(map, map_wiki_data, version),
+    ));
+    if let Some(cats) = reward_cats(&stages, version) {
+        page.push(Section::h2("Reward Cats", cats));
+    }
+    if let Some(materials) = materials(map, version) {
+        page.push(Section::blank(materials));
+    }
+    page.push(Section::h2(
+        "Reference",
+        "*".to_string() + &db_reference(&map.id),
+    ));
+    page.push(Section::blank(format!(
+        "----\n{}\n----",
+        nav(map, event_name)
+    )));
+    page.push(Section::blank(footer(map.id.variant(), event_name)));
+
+    page.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TEST_CONFIG, game_data::meta::stage::map_id::MapID};
+
+    #[test]
+    fn evangelion_finale() {
+        let version = TEST_CONFIG.version.current_version();
+        let finale = GameMap::from_id(MapID::from_components(T::Collab, 209), version);
+        let map_wiki_data = get_map_wiki_data(&finale.id);
+        let event_name = get_event_name(map_wiki_data);
+
+        assert_eq!(event_name, "Neon Genesis Evangelion Collaboration Event");
+        assert!(
+            intro(&finale, map_wiki_data, event_name)
+                .contains(" is a map in the [[Neon Genesis Evangelion Collaboration Event]].")
+        );
+        assert_eq!(
+            footer(finale.id.variant(), event_name),
+            "{{Neon Genesis Evangelion}}\n\
+            {{CollaborationStages List}}\n\
+            [[Category:Collaboration Stages]]"
+        );
+    }
+}
@@ -4,15 +4,27 @@ use crate::{
     game_data::{
         map::{parsed::map::GameMap, raw::map_data::GameMapData},
         meta::stage::{stage_id::StageID, stage_types::transform::transform_map::map_img_code},
+        stage::parsed::stage::{CrownData, Stage},
         version::Version,
     },
-    interface::error_handler::InfallibleWrite,
+    interface::{
+        error_handler::InfallibleWrite,
+        scripts::stage_info::{
+            misc_information::get_continuation_stages, restrictions::restrictions_section,
+            treasure::treasure,
+        },
+    },
     wiki_data::stage_wiki_data::MapWikiData,
     wikitext::text_utils::extract_link,
 };
 use num_format::{Locale, ToFormattedString};
 use std::fmt::Write;
 
+/// Map's background image.
+pub fn map_img(map: &GameMap) -> String {
+    format!("[[File:Map{:03}.png|center|350px]]", map.map_file_num)
+}
+
 /// Table showing what stages are available in the map.
 pub fn stage_table(map_data: &GameMap, map_wiki_data: &MapWikiData, version: &Version) -> String {
     let mapnum = map_data.id.num();
@@ -73,3 +85,279 @@ pub fn stage_table(map_data: &GameMap, map_wiki_data: &MapWikiData, version: &Ve
 
     buf
 }
+
+/// Amount of stages in the map.
+pub fn count_stages(map_data: &GameMap, version: &Version) -> u32 {
+    let mut stage_id = StageID::from_map(map_data.id.clone(), 0);
+    while GameMapData::get_stage_data(&stage_id, version).is_some() {
+        stage_id.set_num(stage_id.num() + 1);
+    }
+
+    stage_id.num()
+}
+
+/// Get every stage in the map.
+pub fn map_stages(map_data: &GameMap, version: &Version) -> Vec<Stage> {
+    (0..count_stages(map_data, version))
+        .map(|i| {
+            map_data
+                .get_stage(i, version)
+                .unwrap_or_else(|e| panic!("Couldn't get stage {i} of {id}: {e}", id = map_data.id))
+        })
+        .collect()
+}
+
+/// Deploy restrictions. If every stage has the same restrictions then they
+/// are only written once, otherwise they are listed by stage, with `label`
+/// being what a stage is called (e.g. `"Floor"`).
+pub fn map_restrictions(stages: &[Stage], label: &str) -> Option<String> {
+    let restrictions = stages.iter().map(restrictions_section).collect::<Vec<_>>();
+
+    if restrictions.iter().all(|r| *r == restrictions[0]) {
+        return restrictions.into_iter().next().flatten();
+    }
+
+    let mut buf = String::new();
+    for (i, restriction) in restrictions.into_iter().enumerate() {
+        let Some(restriction) = restriction else {
+            continue;
+        };
+        // flatten multiple restrictions onto one line
+        let restriction = restriction.trim_start_matches('*').replace("\n*", "; ");
+        writeln!(buf, "*'''{label} {n}:''' {restriction}", n = i + 1).infallible_write();
+    }
+    buf.truncate(buf.trim_end().len());
+
+    if buf.is_empty() { None } else { Some(buf) }
+}
+
+/// Table with every floor's enemies, energy and rewards. `enemies` formats the
+/// enemies cell. The energy column is left out if no floor costs energy.
+pub fn stage_list_table(
+    floors: &[Stage],
+    map_wiki_data: &MapWikiData,
+    enemies: fn(&Stage) -> String,
+    version: &Version,
+) -> String {
+    let has_energy = floors.iter().any(|floor| floor.energy.is_some());
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Floor\n\
+        ! scope=\"col\" | Enemies",
+    );
+    if has_energy {
+        buf.write_str("\n! scope=\"col\" | Energy")
+            .infallible_write();
+    }
+    buf.write_str("\n! scope=\"col\" | Reward")
+        .infallible_write();
+
+    for floor in floors {
+        let num = floor.id.num();
+        let name = match map_wiki_data.get(num) {
+            Some(data) => format!(
+                "[[{link}|Floor {n}]]",
+                link = extract_link(&data.name),
+                n = num + 1
+            ),
+            None => format!("Floor {n}", n = num + 1),
+        };
+        write!(
+            buf,
+            "\n|-\n\
+            ! scope=\"row\" | {name}\n\
+            | {enemies}",
+            enemies = enemies(floor),
+        )
+        .infallible_write();
+
+        if has_energy {
+            match floor.energy {
+                Some(energy) => write!(
+                    buf,
+                    "\n| {energy} {{{{EnergyIcon}}}}",
+                    energy = energy.to_formatted_string(&Locale::en)
+                ),
+                None => write!(buf, "\n| -"),
+            }
+            .infallible_write();
+        }
+
+        match treasure(floor, version) {
+            Some(param) => write!(buf, "\n| {reward}", reward = param.value),
+            None => write!(buf, "\n| -"),
+        }
+        .infallible_write();
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    buf
+}
+
+/// Map's material drops (`{{Materials}}` template), if the map has any.
+pub fn materials(map_data: &GameMap, version: &Version) -> Option<String> {
+    fn format_material(miss_chance: u8, chances: &str) -> String {
+        format!("{{{{Materials|{miss_chance}{chances}}}}}")
+    }
+
+    let drop_item = GameMapData::get_drop_item(&map_data.id, version)?;
+    let normal = [
+        drop_item.bricks,
+        drop_item.feathers,
+        drop_item.coal,
+        drop_item.sprockets,
+        drop_item.gold,
+        drop_item.meteorite,
+        drop_item.beast_bones,
+        drop_item.ammonite,
+    ];
+
+    let mut total = 0;
+    // TODO miss chance is definitely wrong
+    let mut buf = String::new();
+
+    for chance in normal {
+        write!(buf, "|{chance}").unwrap();
+        total += chance;
+    }
+    if drop_item.brick_z.is_none() {
+        return Some(format_material(100 - total, &buf));
+    }
+
+    let drops_z = [
+        drop_item.brick_z,
+        drop_item.feathers_z,
+        drop_item.coal_z,
+        drop_item.sprockets_z,
+        drop_item.gold_z,
+        drop_item.meteorite_z,
+        drop_item.beast_bones_z,
+        drop_item.ammonite_z,
+    ];
+    for chance in drops_z {
+        let chance = chance.unwrap();
+        write!(buf, "|{chance}").unwrap();
+        total += chance;
+    }
+    buf.write_str("|hidenormal=").infallible_write();
+
+    Some(format_material(100 - total, &buf))
+}
+
+/// Table of the magnification applied at each crown difficulty.
+pub fn crown_table(crown_data: &CrownData) -> Option<String> {
+    let max_difficulty = u8::from(crown_data.max_difficulty);
+    if max_difficulty == 1 {
+        return None;
+    }
+
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Difficulty\n\
+        ! scope=\"col\" | Magnification\n\
+        |-\n\
+        | {{1c}}\n\
+        | 100%",
+    );
+
+    let crowns = [crown_data.crown_2, crown_data.crown_3, crown_data.crown_4];
+    for (crown, mag) in (2..=max_difficulty).zip(crowns) {
+        let mag = match mag {
+            Some(mag) => mag.get().to_formatted_string(&Locale::en),
+            None => "100".to_string(),
+        };
+        write!(buf, "\n|-\n| {{{{{crown}c}}}}\n| {mag}%").infallible_write();
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    Some(buf)
+}
+
+/// Continuation stages of every stage in the map.
+pub fn continuations(stages: &[Stage], map_wiki_data: &MapWikiData) -> Option<String> {
+    let lines = stages
+        .iter()
+        .filter_map(|stage| {
+            let continue_data = stage.continue_data.as_ref()?;
+            let name = map_wiki_data
+                .get(stage.id.num())
+                .map_or("?", |data| extract_link(&data.name));
+            let next = get_continuation_stages(continue_data).replace("<br>\n", ", ");
+            Some(format!("*[[{name}]]: {next}"))
+        })
+        .collect::<Vec<_>>();
+
+    if lines.is_empty() {
+        None
+    } else {
+        Some(lines.join("\n"))
+    }
+}
+
+/// Format navigation.
+pub fn nav_item(heading: &str, left: &str, right: &str) -> String {
+    const START: &str = "<p style=\"text-align:center;\">";
+    const END: &str = "</p>";
+
+    format!(
+        "{START}{heading}:{END}\n\n\
+        {START}'''{left} | {right}'''{END}"
+    )
+}
+
+/// Format navigation with links to the previous and next pages.
+pub fn nav_item_opt(heading: &str, left: Option<&str>, right: Option<&str>) -> String {
+    const PREV: &str = "&lt;&lt;";
+    const NEXT: &str = "&gt;&gt;";
+
+    let left = match left {
+        None => format!("{PREV} N/A"),
+        Some(name) => {
+            format!("[[{name}|{PREV} {name}]]")
+        }
+    };
+    let right = match right {
+        None => format!("N/A {NEXT}"),
+        Some(name) => {
+            format!("[[{name}|{name} {NEXT}]]")
+        }
+    };
+
+    nav_item(heading, &left, &right)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::num::{NonZeroU8, NonZeroU32};
+
+    #[test]
+    fn crowns() {
+        let crown_data = CrownData {
+            max_difficulty: NonZeroU8::new(3).unwrap(),
+            crown_2: NonZeroU32::new(150),
+            crown_3: NonZeroU32::new(1_200),
+            crown_4: None,
+        };
+        assert_eq!(
+            crown_table(&crown_data).unwrap(),
+            "{| class=\"article-table\"\n\
+            ! scope=\"col\" | Difficulty\n\
+            ! scope=\"col\" | Magnification\n\
+            |-\n| {{1c}}\n| 100%\n\
+            |-\n| {{2c}}\n| 150%\n\
+            |-\n| {{3c}}\n| 1,200%\n|}"
+        );
+    }
+
+    #[test]
+    fn single_crown() {
+        let crown_data = CrownData {
+            max_difficulty: NonZeroU8::new(1).unwrap(),
+            crown_2: None,
+            crown_3: None,
+            crown_4: None,
+        };
+        assert_eq!(crown_table(&crown_data), None);
+    }
+}
@@ -0,0 +1,266 @@
+//! Catclaw Dojo and Catclaw Championships map info.
+
+use super::{
+    common::{map_img, map_stages, nav_item_opt, stage_table},
+    legend::get_map_wiki_data,
+    map_info::db_reference,
+};
+use crate::{
+    game_data::{
+        map::parsed::map::GameMap, meta::stage::variant::StageVariantID as T,
+        stage::parsed::stage::Stage, version::Version,
+    },
+    interface::{
+        config::Config,
+        error_handler::InfallibleWrite,
+        scripts::stage_info::{
+            information::{base_hp, time_limit},
+            ranking_dojo::bonus_table,
+            treasure::write_name_and_amount,
+        },
+    },
+    wiki_data::stage_wiki_data::{MapWikiData, STAGE_WIKI_DATA},
+    wikitext::{
+        page::Page,
+        section::Section,
+        text_utils::{extract_link, extract_name},
+    },
+};
+use num_format::{Locale, ToFormattedString};
+use std::fmt::Write;
+
+/// Link to the main page of the map's type.
+fn main_link(map: &GameMap) -> &'static str {
+    match map.id.variant() {
+        T::Dojo => "[[Catclaw Dojo]]",
+        T::Championships => "[[Catclaw Championships]]",
+        variant => panic!("{variant:?} is not the Catclaw Dojo or Championships."),
+    }
+}
+
+/// Introduction sentence.
+fn intro(map: &GameMap, map_wiki_data: &MapWikiData, amt_stages: u32) -> String {
+    let kind = match map.id.variant() {
+        T::Championships => "a rank",
+        _ => "a sub-chapter",
+    };
+    format!(
+        "'''{name}''' (?, ''?'', '''?''') is {kind} of the {main} containing \
+        {amt_stages} stages.",
+        name = extract_name(&map_wiki_data.name),
+        main = main_link(map),
+    )
+}
+
+/// Dojo rules and the points each score bonus gives.
+fn score_bonuses(stages: &[Stage]) -> Option<String> {
+    let mut labels = vec![];
+    let mut bonus_types = vec![];
+    for bonuses in stages.iter().filter_map(|stage| stage.bonuses.as_ref()) {
+        if let Some(label) = &bonuses.bonus_name_label
+            && !labels.contains(&label)
+        {
+            labels.push(label);
+        }
+        for bonus in &bonuses.bonus_type {
+            if !bonus_types.contains(bonus) {
+                bonus_types.push(bonus.clone());
+            }
+        }
+    }
+
+    if labels.is_empty() && bonus_types.is_empty() {
+        return None;
+    }
+
+    let mut buf = labels
+        .iter()
+        .map(|label| format!("{{{{DojoRule|{}}}}}", label.as_str()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    if !bonus_types.is_empty() {
+        if !buf.is_empty() {
+            buf += "\n\n";
+        }
+        buf += &bonus_table(&bonus_types);
+    }
+
+    Some(buf)
+}
+
+/// Table of each stage's enemy base HP and time limit.
+fn battle_table(stages: &[Stage], map_wiki_data: &MapWikiData) -> String {
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Stage\n\
+        ! scope=\"col\" | Name\n\
+        ! scope=\"col\" | Enemy Base HP\n\
+        ! scope=\"col\" | Time Limit",
+    );
+
+    for stage in stages {
+        let name = match map_wiki_data.get(stage.id.num()) {
+            Some(data) => format!("[[{link}]]", link = extract_link(&data.name)),
+            None => "?".to_string(),
+        };
+        let hp = base_hp(stage);
+        let hp = hp.first().map_or("-", |param| &param.value);
+        let limit = time_limit(stage);
+        let limit = limit.as_ref().map_or("-", |param| &param.value);
+
+        write!(
+            buf,
+            "\n|-\n\
+            ! scope=\"row\" | Stage {n}\n\
+            | {name}\n\
+            | {hp}\n\
+            | {limit}",
+            n = stage.id.num() + 1,
+        )
+        .infallible_write();
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    buf
+}
+
+/// Table of the rewards for reaching each score in each stage. Returns
+/// [`None`] if no stage has any rewards.
+fn ranking_rewards(stages: &[Stage], version: &Version) -> Option<String> {
+    let mut rows = String::new();
+    for stage in stages {
+        let Some(rewards) = &stage.rewards else {
+            continue;
+        };
+        for reward in &rewards.score_rewards {
+            let mut item = String::new();
+            write_name_and_amount(&mut item, reward.item_id, reward.item_amt, version);
+            write!(
+                rows,
+                "\n|-\n\
+                ! scope=\"row\" | Stage {n}\n\
+                | {score}\n\
+                | {item}",
+                n = stage.id.num() + 1,
+                score = reward.score.to_formatted_string(&Locale::en),
+            )
+            .infallible_write();
+        }
+    }
+
+    if rows.is_empty() {
+        return None;
+    }
+
+    Some(format!(
+        "{{| class=\"article-table\"\n\
+        ! scope=\"col\" | Stage\n\
+        ! scope=\"col\" | Score\n\
+        ! scope=\"col\" | Reward{rows}\n\
+        |}}"
+    ))
+}
+
+/// Navigation to the previous and next seasons.
+fn nav(map: &GameMap) -> String {
+    let type_data = STAGE_WIKI_DATA.stage_type(map.id.variant()).unwrap();
+
+    let prev = match map.id.num() {
+        0 => None,
+        n => type_data.get(n - 1),
+    };
+    let left = prev.map(|data| extract_name(&data.name));
+    let right = type_data
+        .get(map.id.num() + 1)
+        .map(|data| extract_name(&data.name));
+
+    nav_item_opt(main_link(map), left, right)
+}
+
+/// Templates and categories at the bottom of the page.
+fn footer(map: &GameMap) -> &'static str {
+    match map.id.variant() {
+        T::Championships => {
+            "{{DojoStages}}\n\
+            [[Category:Catclaw Championships]]"
+        }
+        _ => {
+            "{{DojoStages}}\n\
+            [[Category:Catclaw Dojo]]"
+        }
+    }
+}
+
+/// Get Catclaw Dojo or Catclaw Championships map info.
+pub fn get_dojo_map(map: &GameMap, config: &Config) -> String {
+    let version = config.version.current_version();
+    let map_wiki_data = get_map_wiki_data(&map.id);
+    let stages = map_stages(map, version);
+
+    let mut page = Page::blank();
+
+    page.push(Section::blank(
+        map_img(map) + "\n" + &intro(map, map_wiki_data, stages.len() as u32),
+    ));
+    if let Some(bonuses) = score_bonuses(&stages) {
+        page.push(Section::h2("Score Bonuses", bonuses));
+    }
+    page.push(Section::h2(
+        "List of Stages",
+        stage_table(map, map_wiki_data, version),
+    ));
+    page.push(Section::h2(
+        "Battlegrounds",
+        battle_table(&stages, map_wiki_data),
+    ));
+    if let Some(rewards) = ranking_rewards(&stages, version) {
+        page.push(Section::h2("Ranking Rewards", rewards));
+    }
+    page.push(Section::h2(
+        "Reference",
+        "*".to_string() + &db_reference(&map.id),
+    ));
+    page.push(Section::blank(format!("----\n{}\n----", nav(map))));
+    page.push(Section::blank(footer(map)));
+
+    page.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TEST_CONFIG,
+        game_data::meta::stage::{map_id::MapID, stage_id::StageID},
+    };
+
+    #[test]
+    fn hall_of_initiates() {
+        let version = TEST_CONFIG.version.current_version();
+        let hall = GameMap::from_id(MapID::from_components(T::Dojo, 0), version);
+        let map_wiki_data = get_map_wiki_data(&hall.id);
+
+        assert!(
+            intro(&hall, map_wiki_data, 2)
+                .contains(" is a sub-chapter of the [[Catclaw Dojo]] containing 2 stages.")
+        );
+        assert_eq!(footer(&hall), "{{DojoStages}}\n[[Category:Catclaw Dojo]]");
+
+        let wanderer = [hall.get_stage(0, version).unwrap()];
+        assert!(battle_table(&wanderer, map_wiki_data).ends_with("\n| Unlimited\n| 5 minutes\n|}"));
+
+        let rewards = ranking_rewards(&wanderer, version).unwrap();
+        assert!(rewards.starts_with("{| class=\"article-table\"\n! scope=\"col\" | Stage"));
+        assert!(rewards.contains("\n|-\n! scope=\"row\" | Stage 1\n| "));
+        assert!(rewards.ends_with("\n|}"));
+    }
+
+    #[test]
+    fn weaken_bonus() {
+        let weaken_dojo =
+            Stage::from_id_current(StageID::from_components(T::RankingDojo, 30, 0)).unwrap();
+        let bonuses = score_bonuses(&[weaken_dojo]).unwrap();
+        assert!(bonuses.starts_with("{{DojoRule|Weaken}}\n\n{| class=\"article-table\""));
+        assert!(bonuses.contains("\n|-\n| Weaken\n| "));
+    }
+}
@@ -1,17 +1,167 @@
 //! Event map info.
 
-use super::legend::get_map_wiki_data;
+use super::{
+    common::{crown_table, map_img, map_restrictions, map_stages, materials},
+    legend::get_map_wiki_data,
+    map_info::db_reference,
+};
 use crate::{
-    game_data::map::parsed::map::GameMap,
-    interface::{config::Config, scripts::map_info::common::stage_table},
+    game_data::{
+        map::parsed::map::{GameMap, ResetType},
+        meta::stage::variant::StageVariantID as T,
+    },
+    interface::{
+        config::Config, error_handler::InfallibleWrite, scripts::map_info::common::stage_table,
+    },
+    wiki_data::stage_wiki_data::MapWikiData,
+    wikitext::{page::Page, section::Section, text_utils::extract_name},
 };
+use std::fmt::Write;
+
+/// Introduction sentences.
+fn intro(map: &GameMap, map_wiki_data: &MapWikiData, config: &Config) -> String {
+    let kind = match map.id.variant() {
+        T::Enigma => "an [[Enigma Stages|Enigma]]",
+        _ => "an [[Special Events|event]]",
+    };
+
+    let mut buf = format!(
+        "'''{name}''' (?, ''?'', '''?''') is {kind} map",
+        name = extract_name(&map_wiki_data.name),
+    );
+
+    if config.map_info.version() {
+        let mut ver = config.version.current_version().number();
+        if let Some(s) = ver.strip_suffix(".0") {
+            ver = s;
+        }
+
+        write!(
+            buf,
+            " that was added in [[Version {ver} Update|Version {ver}]]"
+        )
+        .infallible_write();
+    }
+    buf += ".";
+
+    if let Some(crown_data) = &map.crown_data {
+        write!(
+            buf,
+            " It is available up to {{{{{diff}c}}}} difficulty.",
+            diff = crown_data.max_difficulty
+        )
+        .infallible_write();
+    }
+
+    buf
+}
+
+/// Stage count, clear limits and what resets when the event reappears.
+pub fn overview(map: &GameMap, map_wiki_data: &MapWikiData, amt_stages: u32) -> String {
+    let mut buf = format!(
+        "{name} contains a total of {amt_stages} stages.",
+        name = extract_name(&map_wiki_data.name)
+    );
+
+    if map.hidden_upon_clear {
+        buf += " The map disappears once it has been cleared.";
+    }
+
+    match (map.max_clears, map.cooldown) {
+        (Some(m), Some(c)) => {
+            let m = m.get();
+            let c = c.get();
+            write!(
+                buf,
+                " After beating {m} stages, the player must wait for {c} minutes before \
+                they may play the map again."
+            )
+            .infallible_write();
+        }
+        (Some(m), None) => {
+            write!(
+                buf,
+                " The map disappears after {m} stages have been beaten."
+            )
+            .infallible_write();
+        }
+        (None, Some(c)) => {
+            write!(
+                buf,
+                " After beating a stage, the player must wait for {c} minutes before \
+                they may play the map again."
+            )
+            .infallible_write();
+        }
+        (None, None) => (),
+    }
+
+    match map.reset_type {
+        ResetType::None => (),
+        ResetType::ResetRewards => {
+            buf += " Rewards reset with every appearance of the event.";
+        }
+        ResetType::ResetRewardsAndClear => {
+            buf += " Rewards and clear status reset with every appearance of the event.";
+        }
+        ResetType::ResetMaxClears => {
+            buf += " The amount of plays resets with every appearance of the event.";
+        }
+    }
+
+    buf
+}
+
+/// Templates and categories at the bottom of the page.
+fn footer(map: &GameMap) -> &'static str {
+    match map.id.variant() {
+        T::Enigma => {
+            "{{SpecialStages List}}\n\
+            [[Category:Event Stages]]\n\
+            [[Category:Enigma Stages]]"
+        }
+        _ => {
+            "{{SpecialStages List}}\n\
+            [[Category:Event Stages]]"
+        }
+    }
+}
 
 /// Get event map info.
 pub fn get_event_map(map: &GameMap, config: &Config) -> String {
-    log::warn!("Event map is incomplete.");
-    log::debug!("{map:?}");
+    let version = config.version.current_version();
     let map_wiki_data = get_map_wiki_data(&map.id);
-    stage_table(map, map_wiki_data, config.version.current_version())
+    let stages = map_stages(map, version);
+
+    let mut page = Page::blank();
+
+    page.push(Section::blank(
+        map_img(map) + "\n" + &intro(map, map_wiki_data, config),
+    ));
+    page.push(Section::h2(
+        "Overview",
+        overview(map, map_wiki_data, stages.len() as u32),
+    ));
+    if let Some(table) = map.crown_data.as_ref().and_then(crown_table) {
+        page.push(Section::h2("Difficulty", table));
+    }
+    if let Some(restrictions) = map_restrictions(&stages, "Stage") {
+        page.push(Section::h2("Restrictions", restrictions));
+    }
+    page.push(Section::h2(
+        "List of Stages",
+        stage_table(map, map_wiki_data, version),
+    ));
+    if let Some(materials) = materials(map, version) {
+        page.push(Section::blank(materials));
+    }
+    page.push(Section::h2(
+        "Reference",
+        "*".to_string() + &db_reference(&map.id),
+    ));
+    page.push(Section::blank(footer(map)));
+
+    page.to_string()
 }
 
 /// Only get the table.
@@ -19,3 +169,30 @@ pub fn only_table(map: &GameMap, config: &Config) -> String {
     let map_wiki_data = get_map_wiki_data(&map.id);
     stage_table(map, map_wiki_data, config.version.current_version())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TEST_CONFIG, game_data::meta::stage::map_id::MapID};
+
+    #[test]
+    fn sweet_xp() {
+        let mut config = TEST_CONFIG.clone();
+        config.map_info.set_version(false);
+        config.version.init_all();
+        let version = config.version.current_version();
+
+        let sweet_xp = GameMap::from_id(MapID::from_components(T::Event, 28), version);
+        let map_wiki_data = get_map_wiki_data(&sweet_xp.id);
+
+        assert!(
+            intro(&sweet_xp, map_wiki_data, &config)
+                .contains("(?, ''?'', '''?''') is an [[Special Events|event]] map.")
+        );
+        assert!(overview(&sweet_xp, map_wiki_data, 3).contains(" contains a total of 3 stages."));
+        assert_eq!(
+            footer(&sweet_xp),
+            "{{SpecialStages List}}\n[[Category:Event Stages]]"
+        );
+    }
+}
@@ -3,23 +3,27 @@
 use super::legend::get_map_wiki_data;
 use crate::{
     game_data::{
-        map::{
-            parsed::map::{GameMap, ResetType},
-            raw::map_data::GameMapData,
-        },
-        meta::stage::{map_id::MapID, stage_id::StageID},
+        map::parsed::map::{GameMap, ResetType},
+        meta::stage::variant::StageVariantID as T,
     },
     interface::{
         config::Config,
         error_handler::InfallibleWrite,
-        scripts::map_info::{common::stage_table, map_info::db_reference},
+        scripts::{
+            map_info::{
+                collab,
+                common::{count_stages, stage_table},
+                map_info::db_reference,
+            },
+            stage_info::misc_information::collab_event_name,
+        },
     },
     wiki_data::stage_wiki_data::MapWikiData,
     wikitext::{page::Page, section::Section, text_utils::extract_name},
 };
 use std::fmt::Write;
 
-fn intro(_map: &GameMap, config: &Config, map_wiki_data: &MapWikiData) -> Section {
+fn intro(map: &GameMap, config: &Config, map_wiki_data: &MapWikiData) -> Section {
     let mut buf = String::new();
     let map_name = extract_name(&map_wiki_data.name);
     write!(
@@ -28,6 +32,12 @@ fn intro(_map: &GameMap, config: &Config, map_wiki_data: &MapWikiData) -> Sectio
     )
     .infallible_write();
 
+    if map.id.variant() == T::CollabGauntlet
+        && let Some(event_name) = collab_event_name(&map_wiki_data.name)
+    {
+        write!(buf, " in the [[{event_name}]]").infallible_write();
+    }
+
     if true {
         // if config.map_info.version() {
         let mut ver = config.version.current_version().number();
@@ -48,15 +58,7 @@ fn intro(_map: &GameMap, config: &Config, map_wiki_data: &MapWikiData) -> Sectio
 }
 
 fn overview_section(map: &GameMap, config: &Config, map_wiki_data: &MapWikiData) -> Section {
-    let amt_stages = {
-        let mut stage_id = StageID::from_map(map.id.clone(), 0);
-        let version = config.version.current_version();
-        while GameMapData::get_stage_data(&stage_id, version).is_some() {
-            stage_id.set_num(stage_id.num() + 1);
-        }
-
-        stage_id.num()
-    };
+    let amt_stages = count_stages(map, config.version.current_version());
 
     let mut overview = format!(
         "{name} contains a total of {amt_stages} stages.",
@@ -94,16 +96,24 @@ fn overview_section(map: &GameMap, config: &Config, map_wiki_data: &MapWikiData)
     Section::h2("Overview", overview)
 }
 
-fn page_end(map_id: &MapID) -> String {
+fn page_end(map: &GameMap, map_wiki_data: &MapWikiData) -> String {
     let base = String::from(
         "==First Appearance==\n===English Version===\n*?\n\n===Japanese Version===\n*?\n\n==Reference==\n",
     );
-    base + "*"
-        + &db_reference(map_id)
-        + "\n\n{{SpecialStages List}}\n[[Category:Event Stages]]\n[[Category:Gauntlets]]"
+    let base = base + "*" + &db_reference(&map.id);
+
+    if map.id.variant() == T::CollabGauntlet {
+        let event_name = collab::get_event_name(map_wiki_data);
+        return format!(
+            "{base}\n\n----\n{nav}\n----\n\n{footer}",
+            nav = collab::nav(map, event_name),
+            footer = collab::footer(map.id.variant(), event_name)
+        );
+    }
+    base + "\n\n{{SpecialStages List}}\n[[Category:Event Stages]]\n[[Category:Gauntlets]]"
 }
 
-/// Get gauntlet map info.
+/// Get gauntlet or collab gauntlet map info.
 pub fn get_gauntlet_map(map: &GameMap, config: &Config) -> String {
     log::warn!("Compact gauntlet representation is not complete yet");
     let mut page = Page::blank();
@@ -116,7 +126,7 @@ pub fn get_gauntlet_map(map: &GameMap, config: &Config) -> String {
         "List of Stages",
         stage_table(map, map_wiki_data, config.version.current_version()),
     ));
-    page.push(Section::blank(page_end(&map.id)));
+    page.push(Section::blank(page_end(map, map_wiki_data)));
 
     page.to_string()
 }
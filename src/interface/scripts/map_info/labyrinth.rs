@@ -0,0 +1,83 @@
+//! Labyrinth map info.
+
+use super::{
+    common::{map_restrictions, map_stages, stage_list_table},
+    legend::get_map_wiki_data,
+    map_info::db_reference,
+};
+use crate::{
+    game_data::{map::parsed::map::GameMap, stage::parsed::stage::Stage},
+    interface::{config::Config, scripts::stage_info::enemies_list::enemies_list},
+    wiki_data::stage_wiki_data::MapWikiData,
+    wikitext::{page::Page, section::Section, text_utils::extract_name},
+};
+
+/// Introduction sentences.
+fn intro(map_wiki_data: &MapWikiData, amt_floors: usize) -> Section {
+    Section::blank(format!(
+        "'''{name}''' (?, ''?'', '''?''') is a map in the [[Underground Labyrinth]] \
+        with {amt_floors} floors. Floors do not cost any energy to play.",
+        name = extract_name(&map_wiki_data.name)
+    ))
+}
+
+/// All enemies in the floor with their magnifications.
+fn enemies_with_mags(floor: &Stage) -> String {
+    enemies_list(floor, false)
+        .into_iter()
+        .map(|param| param.value.into_owned())
+        .collect::<Vec<_>>()
+        .join("<br>\n")
+}
+
+/// Templates and categories at the bottom of the page.
+const FOOTER: &str = "{{SpecialStages List}}\n\
+    [[Category:Underground Labyrinth]]";
+
+/// Get labyrinth map info.
+pub fn get_labyrinth_map(map: &GameMap, config: &Config) -> String {
+    let version = config.version.current_version();
+    let map_wiki_data = get_map_wiki_data(&map.id);
+    let floors = map_stages(map, version);
+
+    let mut page = Page::blank();
+
+    page.push(intro(map_wiki_data, floors.len()));
+    if let Some(restrictions) = map_restrictions(&floors, "Floor") {
+        page.push(Section::h2("Restrictions", restrictions));
+    }
+    page.push(Section::h2(
+        "List of Floors",
+        stage_list_table(&floors, map_wiki_data, enemies_with_mags, version),
+    ));
+    page.push(Section::h2(
+        "Reference",
+        "*".to_string() + &db_reference(&map.id),
+    ));
+    page.push(Section::blank(FOOTER));
+
+    page.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TEST_CONFIG,
+        game_data::meta::stage::{map_id::MapID, variant::StageVariantID as T},
+    };
+
+    #[test]
+    fn labyrinth_floors() {
+        let version = TEST_CONFIG.version.current_version();
+        let labyrinth = GameMap::from_id(MapID::from_components(T::Labyrinth, 0), version);
+        let map_wiki_data = get_map_wiki_data(&labyrinth.id);
+
+        let floor_67 = labyrinth.get_stage(66, version).unwrap();
+        assert!(enemies_with_mags(&floor_67).starts_with("{{Magnification|"));
+
+        let table = stage_list_table(&[floor_67], map_wiki_data, enemies_with_mags, version);
+        assert!(!table.contains("Energy"));
+        assert!(table.contains("|Floor 67]]\n| {{Magnification|"));
+    }
+}
@@ -3,15 +3,15 @@
 use super::map_info::db_reference;
 use crate::{
     game_data::{
-        map::{
-            parsed::map::{GameMap, ResetType},
-            raw::map_data::GameMapData,
-        },
+        map::parsed::map::{GameMap, ResetType},
         meta::stage::{map_id::MapID, variant::StageVariantID},
-        version::Version,
     },
     interface::{
-        config::Config, error_handler::InfallibleWrite, scripts::map_info::common::stage_table,
+        config::Config,
+        error_handler::InfallibleWrite,
+        scripts::map_info::common::{
+            continuations, map_img, map_stages, materials, nav_item_opt, stage_table,
+        },
     },
     wiki_data::stage_wiki_data::{MapWikiData, STAGE_WIKI_DATA},
     wikitext::{
@@ -53,11 +53,6 @@ fn test_invariants(map: &GameMap) {
     assert_eq!(map.special_rule, None);
 }
 
-/// Map's background image.
-fn map_img(map: &GameMap) -> String {
-    format!("[[File:Map{:03}.png|center|350px]]", map.map_file_num)
-}
-
 /// Introduction sentences.
 fn intro(map: &GameMap, map_data: &MapWikiData, config: &Config) -> String {
     let mut buf = String::new();
@@ -134,90 +129,6 @@ fn difficulty(map: &GameMap) -> Option<String> {
     Some(buf)
 }
 
-/// Stage's material drops.
-fn materials(map_data: &GameMap, version: &Version) -> String {
-    fn format_material(miss_chance: u8, chances: &str) -> String {
-        format!("{{{{Materials|{miss_chance}{chances}}}}}")
-    }
-
-    let drop_item = GameMapData::get_drop_item(&map_data.id, version).unwrap();
-    let normal = [
-        drop_item.bricks,
-        drop_item.feathers,
-        drop_item.coal,
-        drop_item.sprockets,
-        drop_item.gold,
-        drop_item.meteorite,
-        drop_item.beast_bones,
-        drop_item.ammonite,
-    ];
-
-    let mut total = 0;
-    // TODO miss chance is definitely wrong
-    let mut buf = String::new();
-
-    for chance in normal {
-        write!(buf, "|{chance}").unwrap();
-        total += chance;
-    }
-    if drop_item.brick_z.is_none() {
-        return format_material(100 - total, &buf);
-    }
-
-    let drops_z = [
-        drop_item.brick_z,
-        drop_item.feathers_z,
-        drop_item.coal_z,
-        drop_item.sprockets_z,
-        drop_item.gold_z,
-        drop_item.meteorite_z,
-        drop_item.beast_bones_z,
-        drop_item.ammonite_z,
-    ];
-    for chance in drops_z {
-        let chance = chance.unwrap();
-        write!(buf, "|{chance}").unwrap();
-        total += chance;
-    }
-    buf.write_str("|hidenormal=").infallible_write();
-
-    format_material(100 - total, &buf)
-}
-
-/// Format navigation.
-// TODO this should probably be extracted out somewhere.
-fn nav_item(heading: &str, left: &str, right: &str) -> String {
-    const START: &str = "<p style=\"text-align:center;\">";
-    const END: &str = "</p>";
-
-    format!(
-        "{START}{heading}:{END}\n\n\
-        {START}'''{left} | {right}'''{END}"
-    )
-}
-
-/// Format navigation.
-// TODO this should also probably be extracted out somewhere.
-fn nav_item_opt(heading: &str, left: Option<&str>, right: Option<&str>) -> String {
-    const PREV: &str = "&lt;&lt;";
-    const NEXT: &str = "&gt;&gt;";
-
-    let left = match left {
-        None => format!("{PREV} N/A"),
-        Some(name) => {
-            format!("[[{name}|{PREV} {name}]]")
-        }
-    };
-    let right = match right {
-        None => format!("N/A {NEXT}"),
-        Some(name) => {
-            format!("[[{name}|{name} {NEXT}]]")
-        }
-    };
-
-    nav_item(heading, &left, &right)
-}
-
 /// Navigation menu for map.
 fn nav(map: &GameMap) -> String {
     let type_data = &STAGE_WIKI_DATA.stage_type(map.id.variant()).unwrap();
@@ -270,6 +181,7 @@ pub fn get_legend_map(map: &GameMap, config: &Config) -> String {
     log::warn!("This is not updated to the latest format");
     let map_data = get_map_wiki_data(&map.id);
     let version = &config.version.current_version();
+    let stages = map_stages(map, version);
 
     let mut page = Page::blank();
 
@@ -284,7 +196,12 @@ pub fn get_legend_map(map: &GameMap, config: &Config) -> String {
         "List of Stages",
         stage_table(map, map_data, version),
     ));
-    page.push(Section::blank(materials(map, version)));
+    if let Some(materials) = materials(map, version) {
+        page.push(Section::blank(materials));
+    }
+    if let Some(continuations) = continuations(&stages, map_data) {
+        page.push(Section::h2("Continuation Stages", continuations));
+    }
     page.push(Section::h2(
         "Reference",
         "*".to_string() + &db_reference(&map.id),
@@ -298,7 +215,10 @@ pub fn get_legend_map(map: &GameMap, config: &Config) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::{TEST_CONFIG, game_data::meta::stage::map_id::MapID};
+    use crate::{
+        TEST_CONFIG, game_data::meta::stage::map_id::MapID,
+        interface::scripts::map_info::common::nav_item, wikitext::text_utils::extract_link,
+    };
 
     #[test]
     fn test_full() {
@@ -323,7 +243,7 @@ mod tests {
         );
         // assert_eq!(stage_table(&leg_begins, map_data, version), "");
         assert_eq!(
-            materials(&leg_begins, version),
+            materials(&leg_begins, version).unwrap(),
             "{{Materials|61|13|0|13|13|0|0|0|0}}"
         );
         assert_eq!(
@@ -380,4 +300,68 @@ mod tests {
             It is available up to {{4c}} difficulty."
         );
     }
+
+    #[test]
+    fn zero_legends() {
+        let mut config = TEST_CONFIG.clone();
+        config.map_info.set_version(false);
+        config.version.init_all();
+        let version = config.version.current_version();
+
+        let zl_1 = GameMap::from_id(MapID::from_components(StageVariantID::ZL, 0), version);
+        let map_data = get_map_wiki_data(&zl_1.id);
+        let next = extract_name(
+            &STAGE_WIKI_DATA
+                .stage_map(&MapID::from_components(StageVariantID::ZL, 1))
+                .unwrap()
+                .name,
+        );
+
+        let intro = intro(&zl_1, map_data, &config);
+        assert!(intro.contains(" is the first sub-chapter of "));
+        assert!(intro.contains(", and the 99th sub-chapter overall. "));
+        assert_eq!(
+            nav(&zl_1),
+            nav_item(
+                "[[:Category:Zero Legends Chapters|Zero Legends Chapters]]",
+                "&lt;&lt; N/A",
+                &format!("[[{next}|{next} &gt;&gt;]]")
+            )
+        );
+        assert_eq!(
+            footer(&zl_1),
+            "{{ZeroLegendStages}}\n[[Category:Zero Legends Chapters]]"
+        );
+    }
+
+    #[test]
+    fn zero_legends_continuations() {
+        let version = TEST_CONFIG.version.current_version();
+        let type_data = STAGE_WIKI_DATA.stage_type(StageVariantID::ZL).unwrap();
+
+        let (stages, map_data) = (0..)
+            .map_while(|num| Some((num, type_data.get(num)?)))
+            .map(|(num, map_data)| {
+                let map =
+                    GameMap::from_id(MapID::from_components(StageVariantID::ZL, num), version);
+                (map_stages(&map, version), map_data)
+            })
+            .find(|(stages, _)| stages.iter().any(|stage| stage.continue_data.is_some()))
+            .expect("no Zero Legends map has continuation stages");
+
+        let continuing = stages
+            .iter()
+            .filter(|stage| stage.continue_data.is_some())
+            .collect::<Vec<_>>();
+        let list = continuations(&stages, map_data).unwrap();
+        let lines = list.lines().collect::<Vec<_>>();
+        assert_eq!(lines.len(), continuing.len());
+
+        for (line, stage) in lines.into_iter().zip(continuing) {
+            let name = extract_link(&map_data.get(stage.id.num()).unwrap().name);
+            assert!(line.starts_with(&format!("*[[{name}]]: ")), "{line}");
+            assert!(line.contains(" (''Continuation Stage''"), "{line}");
+            assert!(!line.contains("<br>"), "{line}");
+        }
+    }
 }
@@ -0,0 +1,249 @@
+//! Main chapter and Zombie Outbreak map info.
+
+use super::{common::map_stages, legend::get_map_wiki_data, map_info::db_reference};
+use crate::{
+    game_data::{
+        map::parsed::map::GameMap,
+        meta::stage::{
+            map_id::{MainType, MapID},
+            variant::StageVariantID as T,
+        },
+        stage::parsed::stage::Stage,
+        version::Version,
+    },
+    interface::{
+        config::Config, error_handler::InfallibleWrite, scripts::stage_info::treasure::treasure,
+    },
+    wiki_data::stage_wiki_data::MapWikiData,
+    wikitext::{page::Page, section::Section, text_utils::extract_name},
+};
+use either::Either::{Left, Right};
+use num_format::{Locale, ToFormattedString, WriteFormatted};
+use std::fmt::Write;
+
+/// Which main chapter the map belongs to.
+fn main_type(map: &MapID) -> MainType {
+    match map.variant() {
+        T::MainChapters => map
+            .main_type()
+            .unwrap_or_else(|| panic!("Main chapter {n} out of bounds!", n = map.num())),
+        T::EocOutbreak => MainType::EoC,
+        T::ItfOutbreak => MainType::ItF,
+        T::CotcOutbreak => MainType::CotC,
+        variant => panic!("{variant:?} is not a main chapter or Zombie Outbreak."),
+    }
+}
+
+/// Link to the main chapter's page.
+const fn main_link(main: &MainType) -> &'static str {
+    match main {
+        MainType::EoC => "[[Empire of Cats]]",
+        MainType::ItF => "[[Into the Future]]",
+        MainType::CotC => "[[Cats of the Cosmos]]",
+    }
+}
+
+/// Introduction sentence.
+fn intro(map: &MapID, map_wiki_data: &MapWikiData, amt_stages: u32) -> Section {
+    let name = extract_name(&map_wiki_data.name);
+    let main = main_link(&main_type(map));
+    let kind = if map.variant().is_outbreak() {
+        "[[Zombie Outbreaks|Zombie Outbreak]] map"
+    } else {
+        "map"
+    };
+
+    Section::blank(format!(
+        "'''{name}''' (?, ''?'', '''?''') is a {kind} in {main} containing {amt_stages} stages."
+    ))
+}
+
+/// Stage link without the chapter suffix used in `StageNames.csv` (e.g.
+/// `"[[Korea (Empire of Cats)|Korea]] (Z1)"` becomes
+/// `"[[Korea (Empire of Cats)|Korea]]"`).
+fn stage_link(name: &str) -> &str {
+    match name.rfind("]]") {
+        Some(end) => &name[..end + "]]".len()],
+        None => name,
+    }
+}
+
+/// Label for the stage's row. EoC's map has one Moon stage per chapter, which
+/// are labelled with their chapter number. If the stage's name is missing then
+/// the stage's index is used instead.
+fn stage_label(stage: &Stage, name: &str) -> String {
+    let num = stage.id.num();
+    if stage.id.variant() == T::MainChapters && stage.id.map().num() == 0 && num >= 47 {
+        let chap = name[stage_link(name).len()..].trim();
+        if !chap.is_empty() {
+            return format!("Stage {chap}-48");
+        }
+    }
+    format!("Stage {n}", n = num + 1)
+}
+
+/// Every distinct enemy magnification in the stage.
+fn stage_mags(stage: &Stage) -> String {
+    let mut mags = stage
+        .enemies
+        .iter()
+        .filter(|enemy| !enemy.is_base)
+        .map(|enemy| enemy.magnification)
+        .collect::<Vec<_>>();
+    mags.sort();
+    mags.dedup();
+
+    let mut buf = String::new();
+    for mag in mags {
+        match mag {
+            Left(n) => {
+                buf.write_formatted(&n, &Locale::en).infallible_write();
+                buf += "%";
+            }
+            Right((hp, ap)) => {
+                buf.write_formatted(&hp, &Locale::en).infallible_write();
+                buf += "% HP/";
+                buf.write_formatted(&ap, &Locale::en).infallible_write();
+                buf += "% AP";
+            }
+        }
+        buf += ", ";
+    }
+    buf.truncate(buf.len().saturating_sub(", ".len()));
+
+    buf
+}
+
+/// Table with every stage's energy and treasure, as well as enemy
+/// magnifications if the map is a Zombie Outbreak.
+fn chapter_table(
+    stages: &[Stage],
+    map_wiki_data: &MapWikiData,
+    is_outbreak: bool,
+    version: &Version,
+) -> String {
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Stage\n\
+        ! scope=\"col\" | Name\n\
+        ! scope=\"col\" | Energy\n\
+        ! scope=\"col\" | Treasure",
+    );
+    if is_outbreak {
+        buf.write_str("\n! scope=\"col\" | Magnification")
+            .infallible_write();
+    }
+
+    for stage in stages {
+        let name = map_wiki_data
+            .get(stage.id.num())
+            .map_or("?", |data| data.name.as_str());
+        write!(
+            buf,
+            "\n|-\n\
+            ! scope=\"row\" | {label}\n\
+            | {link}",
+            label = stage_label(stage, name),
+            link = stage_link(name),
+        )
+        .infallible_write();
+
+        match stage.energy {
+            Some(energy) => write!(
+                buf,
+                "\n| {energy} {{{{EnergyIcon}}}}",
+                energy = energy.to_formatted_string(&Locale::en)
+            ),
+            None => write!(buf, "\n| -"),
+        }
+        .infallible_write();
+
+        match treasure(stage, version) {
+            Some(param) => write!(buf, "\n| {treasure}", treasure = param.value),
+            None => write!(buf, "\n| -"),
+        }
+        .infallible_write();
+
+        if is_outbreak {
+            write!(buf, "\n| {mags}", mags = stage_mags(stage)).infallible_write();
+        }
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    buf
+}
+
+/// Get main chapter or Zombie Outbreak map info.
+pub fn get_main_chapter_map(map: &GameMap, config: &Config) -> String {
+    let version = config.version.current_version();
+    let map_wiki_data = get_map_wiki_data(&map.id);
+    let stages = map_stages(map, version);
+
+    let mut page = Page::blank();
+
+    page.push(intro(&map.id, map_wiki_data, stages.len() as u32));
+    page.push(Section::h2(
+        "List of Stages",
+        chapter_table(
+            &stages,
+            map_wiki_data,
+            map.id.variant().is_outbreak(),
+            version,
+        ),
+    ));
+    page.push(Section::h2(
+        "Reference",
+        "*".to_string() + &db_reference(&map.id),
+    ));
+
+    page.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{TEST_CONFIG, game_data::meta::stage::stage_id::StageID};
+
+    #[test]
+    fn stage_links() {
+        assert_eq!(
+            stage_link("[[Korea (Empire of Cats)|Korea]] (Z1)"),
+            "[[Korea (Empire of Cats)|Korea]]"
+        );
+        assert_eq!(
+            stage_link("[[Moon (Empire of Cats)|Moon]] 2"),
+            "[[Moon (Empire of Cats)|Moon]]"
+        );
+        assert_eq!(stage_link("[[Torture Room]]"), "[[Torture Room]]");
+        assert_eq!(stage_link("?"), "?");
+    }
+
+    #[test]
+    fn eoc_moon() {
+        let moon_ch2 =
+            Stage::from_id_current(StageID::from_components(T::MainChapters, 0, 49)).unwrap();
+        assert_eq!(
+            stage_label(&moon_ch2, "[[Moon (Empire of Cats)|Moon]] 2"),
+            "Stage 2-48"
+        );
+        assert_eq!(stage_label(&moon_ch2, "?"), "Stage 50");
+    }
+
+    #[test]
+    fn eoc_outbreak_table() {
+        let version = TEST_CONFIG.version.current_version();
+        let outbreak = GameMap::from_id(MapID::from_components(T::EocOutbreak, 2), version);
+        let map_wiki_data = get_map_wiki_data(&outbreak.id);
+
+        let las_vegas = outbreak.get_stage(43, version).unwrap();
+        assert_eq!(stage_mags(&las_vegas), "600%");
+
+        let table = chapter_table(&[las_vegas], map_wiki_data, true, version);
+        assert!(
+            table.contains(
+                "! scope=\"row\" | Stage 44\n| [[Las Vegas (Empire of Cats)|Las Vegas]]\n"
+            )
+        );
+        assert!(table.ends_with("\n| 600%\n|}"));
+    }
+}
@@ -5,12 +5,15 @@ use crate::{
     interface::{
         cli::{
             base::BaseOptions,
-            cli_util::{CommandExec, ConfigMerge, input},
+            cli_util::{CommandExec, ConfigMerge, input, print_json},
             version_opt::VersionOptions,
         },
         config::Config,
         scripts::map_info::map_info::get_map_info,
+        wiki_api::publish::{PublishOptions, print_or_publish},
     },
+    wiki_data::stage_wiki_data::STAGE_WIKI_DATA,
+    wikitext::text_utils::extract_link,
 };
 use clap::Args;
 
@@ -20,6 +23,9 @@ pub struct MapInfoOptions {
     /// Map selector.
     pub selector: Vec<String>,
 
+    #[command(flatten)]
+    /// Wiki upload options.
+    pub publish: PublishOptions,
     #[command(flatten)]
     /// Global options.
     pub base: BaseOptions,
@@ -42,7 +48,14 @@ impl CommandExec for MapInfoOptions {
         };
 
         let map = GameMap::from_selector(&selector, config.version.current_version()).unwrap();
-        let info = get_map_info(&map, config);
-        println!("{info}");
+        if self.base.is_json() {
+            print_json(&map);
+            return;
+        }
+        let info = get_map_info(&map, config).to_string();
+        let data = STAGE_WIKI_DATA
+            .stage_map(&map.id)
+            .unwrap_or_else(|| panic!("Couldn't find map name: {}", map.id));
+        print_or_publish(&self.publish, extract_link(&data.name), &info, config);
     }
 }
@@ -9,7 +9,10 @@ use crate::{
     interface::{
         config::Config,
         scripts::map_info::{
-            colosseum::get_colosseum_map, event::only_table, gauntlet::get_gauntlet_map,
+            behemoth::get_behemoth_map, collab::get_collab_map, colosseum::get_colosseum_map,
+            dojo::get_dojo_map, event::only_table, gauntlet::get_gauntlet_map,
+            labyrinth::get_labyrinth_map, main_chapters::get_main_chapter_map,
+            tower::get_tower_map,
         },
     },
 };
@@ -22,11 +25,16 @@ pub fn db_reference(map: &MapID) -> String {
 
 /// Types of map that map info is implemented for.
 enum Preset {
-    // Main,
+    Main,
     Legend,
     Event,
+    Collab,
     Gauntlet,
     Colosseum,
+    Tower,
+    Labyrinth,
+    Behemoth,
+    Dojo,
     /// Just the table.
     Table,
 }
@@ -37,18 +45,18 @@ const fn get_preset(st: StageVariantID) -> Option<Preset> {
     type T = StageVariantID;
     match st {
         T::SoL | T::UL | T::ZL => Some(Preset::Legend),
-        T::Event | T::Collab | T::Enigma => Some(Preset::Event),
+        T::Event | T::Enigma => Some(Preset::Event),
+        T::Collab => Some(Preset::Collab),
         T::Gauntlet | T::CollabGauntlet => Some(Preset::Gauntlet),
         T::Colosseum => Some(Preset::Colosseum),
-        T::Dojo | T::RankingDojo | T::Championships => Some(Preset::Table),
+        T::Tower => Some(Preset::Tower),
+        T::Labyrinth => Some(Preset::Labyrinth),
+        T::Behemoth => Some(Preset::Behemoth),
+        T::Dojo | T::Championships => Some(Preset::Dojo),
+        T::RankingDojo => Some(Preset::Table),
+        T::MainChapters | T::EocOutbreak | T::ItfOutbreak | T::CotcOutbreak => Some(Preset::Main),
         //
-        T::MainChapters | T::Filibuster | T::AkuRealms | T::FilibusterOutbreak => None,
-        //
-        T::EocOutbreak | T::ItfOutbreak | T::CotcOutbreak => None,
-        //
-        T::Tower | T::Labyrinth => None,
-        // One-time
-        T::Behemoth => None,
+        T::Filibuster | T::AkuRealms | T::FilibusterOutbreak => None,
         // ???
         T::Challenge => None,
         // Single stage
@@ -63,10 +71,16 @@ pub fn get_map_info(map: &GameMap, config: &Config) -> String {
         unimplemented!("variant {var:?} is not supported", var = map.id.variant());
     };
     match preset {
+        Preset::Main => get_main_chapter_map(map, config),
         Preset::Legend => get_legend_map(map, config),
         Preset::Event => get_event_map(map, config),
+        Preset::Collab => get_collab_map(map, config),
         Preset::Colosseum => get_colosseum_map(map, config),
         Preset::Gauntlet => get_gauntlet_map(map, config),
+        Preset::Tower => get_tower_map(map, config),
+        Preset::Labyrinth => get_labyrinth_map(map, config),
+        Preset::Behemoth => get_behemoth_map(map, config),
+        Preset::Dojo => get_dojo_map(map, config),
         Preset::Table => only_table(map, config),
     }
 }
@@ -1,9 +1,15 @@
 //! Get info about a map.
 
+pub mod behemoth;
+pub mod collab;
 pub mod colosseum;
 pub mod common;
+pub mod dojo;
 pub mod event;
 pub mod gauntlet;
+pub mod labyrinth;
 pub mod legend;
+pub mod main_chapters;
 pub mod map_cli;
 pub mod map_info;
+pub mod tower;
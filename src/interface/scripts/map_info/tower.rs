@@ -0,0 +1,99 @@
+//! Tower map info.
+
+use super::{
+    common::{map_restrictions, map_stages, nav_item_opt, stage_list_table},
+    legend::get_map_wiki_data,
+    map_info::db_reference,
+};
+use crate::{
+    game_data::map::parsed::map::GameMap,
+    interface::{config::Config, scripts::stage_info::beginning::enemies_appearing},
+    wiki_data::stage_wiki_data::{MapWikiData, STAGE_WIKI_DATA},
+    wikitext::{
+        page::Page,
+        section::Section,
+        text_utils::{extract_link, extract_name},
+    },
+};
+
+/// Introduction sentence.
+fn intro(map_wiki_data: &MapWikiData, amt_floors: usize) -> Section {
+    Section::blank(format!(
+        "'''{name}''' (?, ''?'', '''?''') is a [[Tower]] with {amt_floors} floors.",
+        name = extract_name(&map_wiki_data.name)
+    ))
+}
+
+/// Navigation to the previous and next towers.
+fn nav(map: &GameMap) -> String {
+    let type_data = STAGE_WIKI_DATA.stage_type(map.id.variant()).unwrap();
+
+    let prev = match map.id.num() {
+        0 => None,
+        n => type_data.get(n - 1),
+    };
+    let left = prev.map(|data| extract_link(&data.name));
+    let right = type_data
+        .get(map.id.num() + 1)
+        .map(|data| extract_link(&data.name));
+
+    nav_item_opt("[[Towers]]", left, right)
+}
+
+/// Templates and categories at the bottom of the page.
+const FOOTER: &str = "{{SpecialStages List}}\n\
+    [[Category:Event Stages]]\n\
+    [[Category:Towers]]";
+
+/// Get tower map info.
+pub fn get_tower_map(map: &GameMap, config: &Config) -> String {
+    let version = config.version.current_version();
+    let map_wiki_data = get_map_wiki_data(&map.id);
+    let floors = map_stages(map, version);
+
+    let mut page = Page::blank();
+
+    page.push(intro(map_wiki_data, floors.len()));
+    if let Some(restrictions) = map_restrictions(&floors, "Floor") {
+        page.push(Section::h2("Restrictions", restrictions));
+    }
+    page.push(Section::h2(
+        "List of Floors",
+        stage_list_table(&floors, map_wiki_data, enemies_appearing, version),
+    ));
+    page.push(Section::h2(
+        "Reference",
+        "*".to_string() + &db_reference(&map.id),
+    ));
+    page.push(Section::blank(format!("----\n{}\n----", nav(map))));
+    page.push(Section::blank(FOOTER));
+
+    page.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TEST_CONFIG,
+        game_data::meta::stage::{map_id::MapID, variant::StageVariantID as T},
+    };
+
+    #[test]
+    fn heavenly_tower() {
+        let version = TEST_CONFIG.version.current_version();
+        let tower = GameMap::from_id(MapID::from_components(T::Tower, 0), version);
+        let map_wiki_data = get_map_wiki_data(&tower.id);
+
+        assert_eq!(
+            intro(map_wiki_data, 30).to_string(),
+            "'''Heavenly Tower''' (?, ''?'', '''?''') is a [[Tower]] with 30 floors."
+        );
+        assert!(nav(&tower).contains("&lt;&lt; N/A"));
+
+        let floor_30 = tower.get_stage(29, version).unwrap();
+        let table = stage_list_table(&[floor_30], map_wiki_data, enemies_appearing, version);
+        assert!(table.contains("|Floor 30]]\n| {{EnemiesAppearing|"));
+        assert!(table.ends_with("\n|}"));
+    }
+}
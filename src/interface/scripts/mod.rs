@@ -1,8 +1,12 @@
 //! Scripts to run on the cli.
 
 pub mod cat_info;
+pub mod diff_versions;
 pub mod encounters;
+pub mod enemy_info;
+pub mod gacha;
 pub mod gauntlet;
 pub mod map_info;
+pub mod orb_info;
 pub mod read_wiki;
 pub mod stage_info;
@@ -0,0 +1,4 @@
+//! Get info about talent orbs.
+
+pub mod orb_cli;
+pub mod orb_info;
@@ -0,0 +1,65 @@
+//! `orb-info` command.
+
+use super::orb_info::get_orb_info;
+use crate::{
+    game_data::cat::raw::orb::Orbs,
+    interface::{
+        cli::{
+            base::BaseOptions,
+            cli_util::{CommandExec, ConfigMerge, print_json},
+            version_opt::VersionOptions,
+        },
+        config::Config,
+    },
+};
+use clap::Args;
+
+#[derive(Debug, Args, PartialEq)]
+/// Orb info options.
+pub struct OrbInfoOptions {
+    #[command(flatten)]
+    /// Global options.
+    pub base: BaseOptions,
+    #[command(flatten)]
+    /// Version options.
+    pub version: VersionOptions,
+}
+impl ConfigMerge for OrbInfoOptions {
+    fn merge(&self, config: &mut Config) {
+        self.base.merge(config);
+        self.version.merge(config);
+    }
+}
+impl CommandExec for OrbInfoOptions {
+    fn exec(&self, config: &Config) {
+        let version = config.version.current_version();
+        if self.base.is_json() {
+            let orbs = version.get_cached_file::<Orbs>().iter().collect::<Vec<_>>();
+            print_json(&orbs);
+            return;
+        }
+        println!("{}", get_orb_info(version));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::cli::commands::{Cli, Command};
+    use clap::Parser;
+
+    #[test]
+    fn orb_alias() {
+        const ARGS: [&str; 2] = ["run_program", "orb"];
+        let cli = Cli::parse_from(ARGS.iter());
+        assert_eq!(
+            cli,
+            Cli {
+                command: Command::OrbInfo(OrbInfoOptions {
+                    base: Default::default(),
+                    version: Default::default(),
+                }),
+            }
+        );
+    }
+}
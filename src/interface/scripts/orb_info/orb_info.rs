@@ -0,0 +1,97 @@
+//! Script for talent orb info.
+
+use crate::{
+    game_data::{
+        cat::raw::orb::{GRADES, Orb, Orbs},
+        version::Version,
+    },
+    interface::error_handler::InfallibleWrite,
+};
+use std::fmt::Write;
+
+/// Write a single orb's row of the table.
+fn write_orb_row(buf: &mut String, orb: &Orb) {
+    let traits = orb
+        .traits
+        .iter()
+        .map(|enemy_type| format!("[[:Category:{enemy_type} Enemies|{enemy_type}]]"))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let traits = if traits.is_empty() { "-" } else { &traits };
+    write!(
+        buf,
+        "\n|-\n\
+        ! scope=\"row\" | {name}\n\
+        | {traits}",
+        name = orb.name(),
+    )
+    .infallible_write();
+
+    for i in 0..GRADES.len() {
+        match orb.grades.get(i) {
+            Some(values) => write!(buf, "\n| {}", orb.effect.describe(values)),
+            None => write!(buf, "\n| -"),
+        }
+        .infallible_write();
+    }
+}
+
+/// Table of every orb and its effect at each grade.
+pub fn orb_table<'a>(orbs: impl Iterator<Item = &'a Orb>) -> String {
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Orb\n\
+        ! scope=\"col\" | Traits",
+    );
+    for grade in GRADES {
+        write!(buf, "\n! scope=\"col\" | {grade}").infallible_write();
+    }
+
+    for orb in orbs {
+        write_orb_row(&mut buf, orb);
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    buf
+}
+
+/// Get the table of every orb in the version.
+pub fn get_orb_info(version: &Version) -> String {
+    orb_table(version.get_cached_file::<Orbs>().iter())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::game_data::cat::{parsed::stats::form::EnemyType, raw::orb::OrbEffect};
+
+    #[test]
+    fn red_attack() {
+        let orb = Orb {
+            id: 0,
+            effect: OrbEffect::Attack,
+            traits: vec![EnemyType::Red],
+            grades: vec![vec![1], vec![2]],
+        };
+        assert_eq!(
+            orb_table([orb].iter()),
+            "{| class=\"article-table\"\n\
+            ! scope=\"col\" | Orb\n\
+            ! scope=\"col\" | Traits\n\
+            ! scope=\"col\" | D\n\
+            ! scope=\"col\" | C\n\
+            ! scope=\"col\" | B\n\
+            ! scope=\"col\" | A\n\
+            ! scope=\"col\" | S\n\
+            |-\n\
+            ! scope=\"row\" | Red Attack Orb\n\
+            | [[:Category:Red Enemies|Red]]\n\
+            | +1% attack power\n\
+            | +2% attack power\n\
+            | -\n\
+            | -\n\
+            | -\n\
+            |}"
+        );
+    }
+}
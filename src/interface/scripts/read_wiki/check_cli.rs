@@ -0,0 +1,170 @@
+//! `check-page` command.
+
+use super::{
+    check_page::{IGNORED_SECTIONS, diff_pages},
+    wiki_files::get_raw_page,
+};
+use crate::{
+    game_data::stage::parsed::stage::Stage,
+    interface::{
+        cli::{
+            base::LogOptions,
+            cli_util::{CommandExec, ConfigMerge},
+            version_opt::VersionOptions,
+        },
+        config::Config,
+        scripts::{
+            cat_info::cat_info,
+            stage_info::stage_info::{get_stage_info, get_stage_wiki_data},
+        },
+    },
+    wiki_data::cat_data::CAT_DATA,
+    wikitext::{page::Page, text_utils::extract_link},
+};
+use clap::{Args, ValueEnum};
+use std::{fs, path::PathBuf};
+
+#[derive(Debug, Clone, Copy, ValueEnum, PartialEq)]
+/// Type of page to check.
+pub enum PageType {
+    /// Stage page.
+    Stage,
+    /// Cat page.
+    Cat,
+}
+
+#[derive(Debug, Args, PartialEq)]
+/// Check page options.
+pub struct CheckPageOptions {
+    /// Type of page.
+    pub page_type: PageType,
+    /// Stage selector or cat name/id.
+    pub selector: Vec<String>,
+
+    #[arg(short, long)]
+    /// Compare against a local copy of the page instead of the wiki.
+    pub file: Option<PathBuf>,
+    #[arg(long)]
+    /// Base URL of the wiki to get the page from.
+    pub base_url: Option<String>,
+    #[arg(long)]
+    /// Extra section to ignore (can be used multiple times).
+    pub ignore: Vec<String>,
+
+    #[command(flatten)]
+    /// Global options.
+    pub base: LogOptions,
+    #[command(flatten)]
+    /// Version options.
+    pub version: VersionOptions,
+}
+impl ConfigMerge for CheckPageOptions {
+    fn merge(&self, config: &mut Config) {
+        self.base.merge(config);
+        self.version.merge(config);
+
+        if let Some(base_url) = &self.base_url {
+            config.wiki.base_url = Some(base_url.clone());
+        }
+    }
+}
+
+/// Get the cat's wiki id from its name or id.
+fn get_cat_id(name_or_id: &str) -> u32 {
+    match name_or_id.parse() {
+        Ok(id) => id,
+        Err(_) => match CAT_DATA.get_id_from_name(name_or_id) {
+            Some(id) => id,
+            None => panic!("{name_or_id:?} is not a valid number or cat form name!"),
+        },
+    }
+}
+
+impl CheckPageOptions {
+    /// Get the name of the page on the wiki and the generated page.
+    fn generate(&self, config: &Config) -> (String, String) {
+        let selector = self.selector.join(" ");
+        match self.page_type {
+            PageType::Stage => {
+                let stage = Stage::from_selector(&selector, config.version.current_version())
+                    .unwrap_or_else(|e| {
+                        panic!("Error when getting info for stage {selector:?}: {e}")
+                    });
+                let data = get_stage_wiki_data(&stage.id);
+                let page_name = extract_link(&data.stage_name.name).to_string();
+                (page_name, get_stage_info(&stage, config).to_string())
+            }
+            PageType::Cat => {
+                let id = get_cat_id(&selector);
+                let page_name = CAT_DATA.get_cat(id).page.clone();
+                let page = cat_info::get_info(id, config).unwrap();
+                (page_name, page.to_string())
+            }
+        }
+    }
+}
+
+impl CommandExec for CheckPageOptions {
+    fn exec(&self, config: &Config) {
+        let (page_name, generated) = self.generate(config);
+
+        let live = match &self.file {
+            Some(path) => fs::read_to_string(path)
+                .unwrap_or_else(|e| panic!("Couldn't read file {path:?}: {e}")),
+            None => get_raw_page(&page_name, config)
+                .unwrap_or_else(|e| panic!("Couldn't get page {page_name:?} from the wiki: {e}")),
+        };
+
+        let ignored = IGNORED_SECTIONS
+            .into_iter()
+            .chain(self.ignore.iter().map(String::as_str))
+            .collect::<Vec<_>>();
+        let diff = diff_pages(
+            &Page::from_wikitext(&live),
+            &Page::from_wikitext(&generated),
+            &ignored,
+        );
+
+        if diff.is_empty() {
+            println!("{page_name}: no differences found.");
+        } else {
+            println!("{page_name}:\n{diff}");
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::cli::commands::{Cli, Command};
+    use clap::Parser;
+
+    #[test]
+    fn check_local_file() {
+        const ARGS: [&str; 8] = [
+            "run_program",
+            "check-page",
+            "stage",
+            "l 0 0",
+            "-f",
+            "page.txt",
+            "--ignore",
+            "Trivia",
+        ];
+        let cli = Cli::parse_from(ARGS.iter());
+        assert_eq!(
+            cli,
+            Cli {
+                command: Command::CheckPage(CheckPageOptions {
+                    page_type: PageType::Stage,
+                    selector: ["l 0 0".into()].into(),
+                    file: Some("page.txt".into()),
+                    base_url: None,
+                    ignore: ["Trivia".into()].into(),
+                    base: Default::default(),
+                    version: Default::default(),
+                }),
+            }
+        );
+    }
+}
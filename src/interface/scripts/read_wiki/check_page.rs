@@ -0,0 +1,149 @@
+//! Compare a generated page against the version on the wiki.
+
+use super::wiki_files::get_file_diff;
+use crate::{
+    interface::error_handler::InfallibleWrite,
+    wikitext::{page::Page, section::SectionTitle},
+};
+use std::fmt::Write;
+
+/// Sections that are written by editors rather than generated.
+pub const IGNORED_SECTIONS: [&str; 2] = ["Strategy", "Strategy/Usage"];
+
+/// Readable heading of a section, used to identify it in the diff.
+fn heading(title: &SectionTitle) -> String {
+    match title {
+        SectionTitle::Blank => "(top of page)".to_string(),
+        title => {
+            let equals = "=".repeat(title.level().into());
+            format!("{equals}{text}{equals}", text = title.text())
+        }
+    }
+}
+
+/// Get all sections of `page` that should be compared, as `(heading,
+/// content)`. If a section is ignored then so are all of its subsections.
+/// Repeated headings get their occurrence number appended.
+fn comparable_sections<'a>(page: &'a Page, ignored: &[&str]) -> Vec<(String, &'a str)> {
+    let mut sections: Vec<(String, &str)> = vec![];
+    let mut ignored_level = None;
+
+    for section in page.sections() {
+        let level = section.title.level();
+        if let Some(ignored) = ignored_level {
+            if level > ignored {
+                continue;
+            }
+            ignored_level = None;
+        }
+        if ignored.contains(&section.title.text()) {
+            ignored_level = Some(level);
+            continue;
+        }
+
+        let mut key = heading(&section.title);
+        let count = sections
+            .iter()
+            .filter(|(k, _)| k == &key || k.starts_with(&format!("{key} (")))
+            .count();
+        if count > 0 {
+            write!(key, " ({n})", n = count + 1).infallible_write();
+        }
+
+        sections.push((key, section.content.trim()));
+    }
+
+    sections
+}
+
+/// Get a section-by-section diff between the page on the wiki and the
+/// generated page. Sections whose names are in `ignored` are skipped. Returns
+/// an empty string if the pages are the same.
+pub fn diff_pages(live: &Page, generated: &Page, ignored: &[&str]) -> String {
+    let live = comparable_sections(live, ignored);
+    let mut generated = comparable_sections(generated, ignored);
+
+    let mut buf = String::new();
+    for (key, live_content) in live {
+        let Some(pos) = generated.iter().position(|(k, _)| *k == key) else {
+            writeln!(buf, "{key}: only on wiki\n").infallible_write();
+            continue;
+        };
+        let (_, generated_content) = generated.remove(pos);
+        if live_content == generated_content {
+            continue;
+        }
+
+        let diff = get_file_diff(
+            &(live_content.to_string() + "\n"),
+            &(generated_content.to_string() + "\n"),
+        );
+        writeln!(buf, "{key}\n{diff}").infallible_write();
+    }
+
+    for (key, generated_content) in generated {
+        writeln!(buf, "{key}: missing from wiki\n{generated_content}\n").infallible_write();
+    }
+
+    buf
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::wikitext::section::Section;
+
+    fn page(sections: Vec<Section>) -> Page {
+        let mut page = Page::blank();
+        for section in sections {
+            page.push(section);
+        }
+        page
+    }
+
+    #[test]
+    fn same_page() {
+        let live = page(vec![
+            Section::blank("Intro."),
+            Section::h2("Strategy", "Use cats."),
+            Section::h3("Cheese", "Use more cats."),
+            Section::h2("Reference", "*link"),
+        ]);
+        let generated = page(vec![
+            Section::blank("Intro."),
+            Section::h2("Strategy", "-"),
+            Section::h2("Reference", "*link"),
+        ]);
+        assert_eq!(diff_pages(&live, &generated, &IGNORED_SECTIONS), "");
+    }
+
+    #[test]
+    fn changed_sections() {
+        let live = page(vec![
+            Section::blank("Intro."),
+            Section::h2("Trivia", "Fact."),
+            Section::h2("Reference", "*old link"),
+        ]);
+        let generated = page(vec![
+            Section::blank("Intro."),
+            Section::h2("Reference", "*new link"),
+            Section::h2("Rules", "Rules."),
+        ]);
+        let diff = diff_pages(&live, &generated, &IGNORED_SECTIONS);
+
+        assert!(!diff.contains("(top of page)"));
+        assert!(diff.contains("==Trivia==: only on wiki\n"));
+        assert!(diff.contains("==Reference==\n"));
+        assert!(diff.contains("-*old link"));
+        assert!(diff.contains("+*new link"));
+        assert!(diff.ends_with("==Rules==: missing from wiki\nRules.\n\n"));
+    }
+
+    #[test]
+    fn repeated_headings() {
+        let live = page(vec![Section::h3("Stats", "a"), Section::h3("Stats", "b")]);
+        let generated = page(vec![Section::h3("Stats", "a"), Section::h3("Stats", "c")]);
+        let diff = diff_pages(&live, &generated, &[]);
+        assert!(diff.starts_with("===Stats=== (2)\n"));
+    }
+}
@@ -1,4 +1,6 @@
 //! Module for the `read-wiki` command.
 
+pub mod check_cli;
+pub mod check_page;
 pub mod wiki_cli;
 pub mod wiki_files;
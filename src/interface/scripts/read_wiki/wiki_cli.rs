@@ -1,12 +1,16 @@
 //! `read_wiki` command.
 
-use crate::interface::{
-    cli::{
-        base::BaseOptions,
-        cli_util::{CommandExec, ConfigMerge},
+use crate::{
+    game_data::item::gatya_item::GatyaItems,
+    interface::{
+        cli::{
+            base::LogOptions,
+            cli_util::{CommandExec, ConfigMerge},
+        },
+        config::Config,
+        scripts::read_wiki::wiki_files::update_wiki_files,
     },
-    config::Config,
-    scripts::read_wiki::wiki_files::update_wiki_files,
+    wiki_data::rewards::TREASURE_DATA,
 };
 use clap::Args;
 
@@ -18,7 +22,7 @@ pub struct ReadWikiOptions {
 
     #[command(flatten)]
     /// Global options.
-    pub base: BaseOptions,
+    pub base: LogOptions,
 }
 
 impl ConfigMerge for ReadWikiOptions {
@@ -33,5 +37,18 @@ impl ConfigMerge for ReadWikiOptions {
 impl CommandExec for ReadWikiOptions {
     fn exec(&self, config: &Config) {
         update_wiki_files(config);
+
+        let items = config
+            .version
+            .current_version()
+            .get_cached_file::<GatyaItems>();
+        let missing = TREASURE_DATA.missing_items(items);
+        if missing.is_empty() {
+            return;
+        }
+        println!("Items missing from Treasures.csv:");
+        for (id, name) in missing {
+            println!("{id:03} {name}");
+        }
     }
 }
@@ -6,7 +6,6 @@ use std::{
     fs::File,
     io::{Read, Write},
 };
-const WIKI_URL: &str = "https://battlecats.miraheze.org/wiki";
 
 /// (`file_name`, `page_name`)
 const FILES: [(&str, &str); 8] = [
@@ -29,7 +28,7 @@ const FILES: [(&str, &str); 8] = [
 const USER_AGENT: &str = "user-agent";
 
 /// Get a coloured unified diff between the old and new content.
-fn get_file_diff(old_content: &str, new_content: &str) -> String {
+pub fn get_file_diff(old_content: &str, new_content: &str) -> String {
     // This is largely copied from the implementation of `similar`'s unified
     // diff's format trait.
     let binding = TextDiff::configure().diff_lines(old_content, new_content);
@@ -66,20 +65,40 @@ fn strip_pre(content: &str) -> &str {
     }
 }
 
+/// Convert a page name into the form used in URLs.
+fn page_url_name(page_name: &str) -> String {
+    let mut buf = String::new();
+    for c in page_name.chars() {
+        match c {
+            ' ' => buf.push('_'),
+            '%' | '?' | '&' | '#' | '+' => buf += &format!("%{:02X}", c as u32),
+            c => buf.push(c),
+        }
+    }
+    buf
+}
+
+/// Get the raw wikitext of `page_name` from the wiki.
+pub fn get_raw_page(page_name: &str, config: &Config) -> Result<String, ureq::Error> {
+    let user_agent = format!("{}/rust-wiki-reader", config.wiki.username);
+    let uri = format!(
+        "{base}/{page}?action=raw",
+        base = config.wiki.base_url(),
+        page = page_url_name(page_name)
+    );
+    let response = ureq::get(&uri).header(USER_AGENT, &user_agent).call()?;
+    response.into_body().read_to_string()
+}
+
 /// Goes through all files stored on teh wiki and updates the local versions of
 /// each.
 pub fn update_wiki_files(config: &Config) {
     let directory = get_wiki_data_location();
     std::fs::create_dir_all(directory).unwrap();
 
-    let user_agent = format!("{}/rust-wiki-reader", config.wiki.username);
     for (file_name, page_name) in FILES {
-        let uri = format!("{WIKI_URL}/{page_name}?action=raw");
-        let response = ureq::get(&uri)
-            .header(USER_AGENT, &user_agent)
-            .call()
-            .expect("Error: couldn't get the data from the wiki.");
-        let mut res_str = response.into_body().read_to_string().unwrap();
+        let mut res_str =
+            get_raw_page(page_name, config).expect("Error: couldn't get the data from the wiki.");
         res_str.push('\n');
         let content = strip_pre(&res_str);
 
@@ -113,3 +132,15 @@ pub fn update_wiki_files(config: &Config) {
         f_write.write_all(content.as_bytes()).unwrap();
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn url_names() {
+        assert_eq!(page_url_name("Module:Enemies.csv"), "Module:Enemies.csv");
+        assert_eq!(page_url_name("Crazed Cat (Deadly)"), "Crazed_Cat_(Deadly)");
+        assert_eq!(page_url_name("Cats & Dogs?"), "Cats_%26_Dogs%3F");
+    }
+}
@@ -0,0 +1,159 @@
+//! Get the effective stats of enemies in the stage.
+
+use crate::{
+    game_data::{
+        enemy::{parsed::stats::EnemyStats, raw::stats::EnemyStatsContainer},
+        stage::parsed::{
+            stage::Stage,
+            stage_enemy::{MS_SIGN, Magnification, StageEnemy},
+        },
+        version::Version,
+    },
+    interface::error_handler::InfallibleWrite,
+    wiki_data::enemy_data::ENEMY_DATA,
+};
+use either::Either::{Left, Right};
+use num_format::{Locale, WriteFormatted};
+use std::{collections::HashSet, fmt::Write};
+
+/// Get the crown multipliers of the stage (raw %, i.e. 100 = *1).
+fn crown_multipliers(stage: &Stage) -> Vec<u32> {
+    let mut multipliers = vec![100];
+    let Some(crowns) = &stage.crown_data else {
+        return multipliers;
+    };
+
+    let difficulty: u8 = crowns.max_difficulty.into();
+    let others = [crowns.crown_2, crowns.crown_3, crowns.crown_4];
+    for crown in others.into_iter().take(usize::from(difficulty) - 1) {
+        let crown: u32 = crown.expect("crown should exist if below max").into();
+        multipliers.push(crown);
+    }
+    if multipliers.len() == 4 && multipliers[3] == 100 {
+        // same as `enemies_list`
+        multipliers.pop();
+    }
+
+    multipliers
+}
+
+/// Write the enemy's name and magnification to `buf`.
+fn write_enemy_mag(buf: &mut String, id: u32, magnification: &Magnification) {
+    write!(buf, "{} (", ENEMY_DATA.get_names(id).name).infallible_write();
+    match magnification {
+        Left(mag) => {
+            buf.write_formatted(mag, &Locale::en).infallible_write();
+            buf.write_str("%)").infallible_write();
+        }
+        Right((hp, ap)) => {
+            buf.write_formatted(hp, &Locale::en).infallible_write();
+            buf.write_str("% HP, ").infallible_write();
+            buf.write_formatted(ap, &Locale::en).infallible_write();
+            buf.write_str("% AP)").infallible_write();
+        }
+    }
+}
+
+/// Write effective HP and AP of the enemy to `buf`. `multiplier` is raw %.
+fn write_effective_stats(
+    buf: &mut String,
+    stats: &EnemyStats,
+    magnification: &Magnification,
+    multiplier: u32,
+) {
+    let (hp_mag, ap_mag) = match magnification {
+        Left(mag) => (*mag, *mag),
+        Right((hp, ap)) => (*hp, *ap),
+    };
+    let hp = stats.hp_at_magnification(hp_mag * multiplier / 100);
+    let ap = stats.ap_at_magnification(ap_mag * multiplier / 100);
+
+    buf.write_formatted(&hp, &Locale::en).infallible_write();
+    buf.write_str(" HP<br>").infallible_write();
+    buf.write_formatted(&ap, &Locale::en).infallible_write();
+    buf.write_str(" AP").infallible_write();
+}
+
+/// Get a table of each enemy's HP and AP after magnification and crown
+/// multipliers have been applied. Returns [`None`] if stage has no enemies.
+pub fn effective_stats(stage: &Stage, version: &Version) -> Option<String> {
+    let mut seen = HashSet::new();
+    let enemies = stage
+        .enemies
+        .iter()
+        .filter(|e| !e.is_base && e.id != MS_SIGN && seen.insert((e.id, e.magnification)))
+        .collect::<Vec<&StageEnemy>>();
+    if enemies.is_empty() {
+        return None;
+    }
+
+    let container = version.get_cached_file::<EnemyStatsContainer>();
+    let multipliers = crown_multipliers(stage);
+
+    let mut buf = String::from("{| class=\"article-table\"\n!Enemy");
+    if multipliers.len() == 1 {
+        buf += "\n!Stats";
+    } else {
+        for (i, multiplier) in multipliers.iter().enumerate() {
+            write!(buf, "\n!{crowns}★", crowns = i + 1).infallible_write();
+            if *multiplier != 100 {
+                buf += " (";
+                buf.write_formatted(multiplier, &Locale::en)
+                    .infallible_write();
+                buf += "%)";
+            }
+        }
+    }
+
+    for enemy in enemies {
+        let Some(combined) = container.get_enemy(enemy.id) else {
+            log::warn!("Enemy {id} not found in t_unit.csv.", id = enemy.id);
+            continue;
+        };
+        let stats = EnemyStats::from_combined(combined);
+
+        buf += "\n|-\n|";
+        write_enemy_mag(&mut buf, enemy.id, &enemy.magnification);
+        for multiplier in &multipliers {
+            buf += "\n|";
+            write_effective_stats(&mut buf, &stats, &enemy.magnification, *multiplier);
+        }
+    }
+    buf += "\n|}";
+
+    Some(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        TEST_CONFIG,
+        game_data::{
+            enemy::raw::stats::CombinedEnemyData, meta::stage::stage_id::StageID,
+            meta::stage::variant::StageVariantID as T,
+        },
+    };
+
+    #[test]
+    fn split_magnification() {
+        let mut combined = CombinedEnemyData::default();
+        combined.0.hp = 1_000;
+        combined.0.atk = 100;
+        let stats = EnemyStats::from_combined(&combined);
+
+        let mut buf = String::new();
+        write_effective_stats(&mut buf, &stats, &Right((200, 50)), 150);
+        assert_eq!(buf, "3,000 HP<br>75 AP");
+    }
+
+    #[test]
+    fn aac() {
+        let aac = Stage::from_id_current(StageID::from_components(T::UL, 0, 0)).unwrap();
+        let version = TEST_CONFIG.version.current_version();
+        let table = effective_stats(&aac, version).unwrap();
+        assert!(
+            table.starts_with("{| class=\"article-table\"\n!Enemy\n!1★\n!2★ (150%)\n!3★ (200%)")
+        );
+    }
+}
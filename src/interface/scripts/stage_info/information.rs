@@ -6,7 +6,7 @@ use crate::{
             stage_types::transform::transform_map::map_img_code, variant::StageVariantID,
         },
         stage::parsed::stage::Stage,
-        version::lang::VersionLanguage,
+        version::{Version, lang::VersionLanguage},
     },
     interface::error_handler::InfallibleWrite,
     wiki_data::{enemy_data::ENEMY_DATA, rewards::TREASURE_DATA},
@@ -58,22 +58,22 @@ pub fn stage_location(stage: &Stage, lang: VersionLanguage) -> TemplateParameter
     TemplateParameter::new("stage location", buf)
 }
 
-fn energy_catamin(cost: u32) -> TemplateParameter {
+fn energy_catamin(cost: u32, version: &Version) -> TemplateParameter {
     const CATAMIN_A_ID: u32 = 55;
 
     // e.g. 2002 for Catamin C x2
     let catamin_type = cost / 1000;
     let amount = cost % 1000;
-    let name = TREASURE_DATA.get_treasure_name(CATAMIN_A_ID + catamin_type);
+    let name = TREASURE_DATA.get_treasure_name(CATAMIN_A_ID + catamin_type, version);
 
     TemplateParameter::new("catamins", format!("{name} x{amount}"))
 }
 
 /// Get the `|energy` parameter.
-pub fn energy(stage: &Stage) -> Option<TemplateParameter> {
+pub fn energy(stage: &Stage, version: &Version) -> Option<TemplateParameter> {
     let energy = stage.energy?;
     let amount = match stage.id.variant() {
-        StageVariantID::Catamin => return Some(energy_catamin(energy)),
+        StageVariantID::Catamin => return Some(energy_catamin(energy, version)),
         StageVariantID::Extra => "N/A".to_string(),
         _ => {
             let mut buf = String::new();
@@ -177,7 +177,7 @@ pub fn time_limit(stage: &Stage) -> Option<TemplateParameter> {
             format!(
                 "{limit} {minutes}",
                 limit = t.get(),
-                minutes = plural(t.get() as u16, "minute", "minutes")
+                minutes = plural(t.get(), "minute", "minutes")
             ),
         )
     })
@@ -217,7 +217,7 @@ pub fn max_enemies(stage: &Stage) -> TemplateParameter {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game_data::meta::stage::stage_id::StageID;
+    use crate::{TEST_CONFIG, game_data::meta::stage::stage_id::StageID};
     use StageVariantID as T;
 
     #[test]
@@ -291,7 +291,10 @@ mod tests {
     #[test]
     fn test_energy_normal() {
         let aac = Stage::from_id_current(StageID::from_components(T::UL, 0, 0)).unwrap();
-        assert_eq!(energy(&aac), Some(TemplateParameter::new("energy", "200")));
+        assert_eq!(
+            energy(&aac, TEST_CONFIG.version.current_version()),
+            Some(TemplateParameter::new("energy", "200"))
+        );
     }
 
     #[test]
@@ -299,7 +302,7 @@ mod tests {
         let challenge =
             Stage::from_id_current(StageID::from_components(T::Challenge, 0, 0)).unwrap();
         assert_eq!(
-            energy(&challenge),
+            energy(&challenge, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new("energy", "0"))
         );
     }
@@ -308,7 +311,7 @@ mod tests {
     fn test_energy_ex() {
         let door_opens = Stage::from_id_current(StageID::from_components(T::Extra, 47, 0)).unwrap();
         assert_eq!(
-            energy(&door_opens),
+            energy(&door_opens, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new("energy", "N/A"))
         );
     }
@@ -318,7 +321,7 @@ mod tests {
         let facing_danger =
             Stage::from_id_current(StageID::from_components(T::Catamin, 5, 0)).unwrap();
         assert_eq!(
-            energy(&facing_danger),
+            energy(&facing_danger, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new("catamins", "[[Catamin]] [C] x2"))
         );
     }
@@ -328,7 +331,7 @@ mod tests {
         let mining_epic =
             Stage::from_id_current(StageID::from_components(T::Event, 326, 0)).unwrap();
         assert_eq!(
-            energy(&mining_epic),
+            energy(&mining_epic, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new("energy", "1,000"))
         );
     }
@@ -337,7 +340,10 @@ mod tests {
     fn test_energy_labyrinth() {
         let labyrinth_67 =
             Stage::from_id_current(StageID::from_components(T::Labyrinth, 0, 66)).unwrap();
-        assert_eq!(energy(&labyrinth_67), None);
+        assert_eq!(
+            energy(&labyrinth_67, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -6,6 +6,7 @@ use crate::{
         meta::stage::{map_id::MapID, stage_id::StageID, variant::StageVariantID as T},
         stage::parsed::stage::{ContinueStages, Stage},
     },
+    interface::scripts::map_info::collab,
     regex_handler::static_regex,
     wiki_data::stage_wiki_data::{MapWikiData, STAGE_WIKI_DATA, StageWikiData},
     wikitext::{
@@ -25,6 +26,51 @@ pub fn star(stage: &Stage) -> TemplateParameter {
     TemplateParameter::new("star", max_crowns.to_string())
 }
 
+/// Get the name of the collaboration event page from a collab map's name
+/// (e.g. `"Neon Genesis Evangelion Collaboration Event"`).
+pub fn collab_event_name(map_name: &str) -> Option<&str> {
+    let collab_name = static_regex(r"\[\[(.*? Event)");
+    collab_name
+        .captures_iter(map_name)
+        .next()
+        .map(|c| c.get(1).unwrap().as_str())
+}
+
+/// Templates at the bottom of a collab page: the collab's own navbox and the
+/// list of collaboration stages.
+pub fn collab_templates(event_name: &str) -> String {
+    let collab = event_name
+        .strip_suffix(" Collaboration Event")
+        .unwrap_or(event_name);
+    format!("{{{{{collab}}}}}\n{{{{CollaborationStages List}}}}")
+}
+
+/// Maintenance templates at the top of a collab stage's page.
+pub const COLLAB_MAINTENANCE: &str =
+    "{{LimitedContent}}\n{{StrategyNeeded}}\n{{TranslationNeeded}}";
+
+/// Templates and categories at the bottom of a collab stage's page.
+pub fn collab_footer(stage: &Stage, data: &StageWikiDataContainer) -> Option<String> {
+    if !matches!(stage.id.variant(), T::Collab | T::CollabGauntlet) {
+        return None;
+    }
+
+    let event_name = collab_event_name(&data.stage_map.name).unwrap_or("name");
+    let mut buf = collab::footer(stage.id.variant(), event_name);
+    if stage.is_no_continues {
+        buf += "\n[[Category:No Continue Stages]]";
+    }
+    if stage
+        .rewards
+        .as_ref()
+        .is_some_and(|rewards| !rewards.score_rewards.is_empty())
+    {
+        buf += "\n[[Category:Timed Score Stages]]";
+    }
+
+    Some(buf)
+}
+
 /// Get the `event`, `event-chapter` or `sub-chapter` items.
 pub fn chapter(stage: &Stage, data: &StageWikiDataContainer) -> Vec<TemplateParameter> {
     fn get_map_name(map: &MapWikiData) -> String {
@@ -44,11 +90,7 @@ pub fn chapter(stage: &Stage, data: &StageWikiDataContainer) -> Vec<TemplatePara
             get_map_name(data.stage_map),
         )],
         T::Collab | T::CollabGauntlet => {
-            let collab_name = static_regex(r"\[\[(.*? Event)");
-            let collab_name = match collab_name.captures_iter(&data.stage_map.name).next() {
-                Some(c) => c.get(1).unwrap().as_str(),
-                None => "name",
-            };
+            let collab_name = collab_event_name(&data.stage_map.name).unwrap_or("name");
 
             let event = TemplateParameter::new("event", format!("[[{collab_name}]]"));
             vec![
@@ -114,7 +156,7 @@ fn get_single_nav(location: Option<&StageWikiData>) -> String {
 }
 
 /// Get all continuation stages possible from current stage.
-fn get_continuation_stages(data: &ContinueStages) -> String {
+pub fn get_continuation_stages(data: &ContinueStages) -> String {
     let map_id: MapID = MapID::from_numbers(4, data.map_id);
     let map = STAGE_WIKI_DATA
         .stage_map(&map_id)
@@ -357,4 +399,19 @@ mod tests {
             ]
         );
     }
+
+    #[test]
+    fn collab_templates_and_categories() {
+        assert_eq!(
+            collab_templates("Neon Genesis Evangelion Collaboration Event"),
+            "{{Neon Genesis Evangelion}}\n{{CollaborationStages List}}"
+        );
+        assert_eq!(
+            collab_event_name(
+                "[[Neon Genesis Evangelion Collaboration Event#Bye-bye, all of the Cats|Bye-bye, all of the Cats]]"
+            ),
+            Some("Neon Genesis Evangelion Collaboration Event")
+        );
+        assert_eq!(collab_event_name("Placeholder"), None);
+    }
 }
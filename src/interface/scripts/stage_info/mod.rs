@@ -2,12 +2,15 @@
 
 pub mod battlegrounds;
 pub mod beginning;
+pub mod effective_stats;
 pub mod enemies_list;
 pub mod information;
 pub mod misc_information;
+pub mod ranking_dojo;
 pub mod restrictions;
 pub mod stage_cli;
 pub mod stage_info;
 pub mod treasure;
+pub mod zero_legends;
 
 mod tests;
@@ -0,0 +1,114 @@
+//! Deals with the extra sections of Ranking Dojo stages.
+
+use crate::{
+    game_data::{
+        map::cached::score_bonus::BonusType, meta::stage::variant::StageVariantID as T,
+        stage::parsed::stage::Stage,
+    },
+    interface::error_handler::InfallibleWrite,
+    wikitext::number_utils::plural,
+};
+use num_format::{Locale, ToFormattedString};
+use std::fmt::Write;
+
+/// Rules that are the same in every Ranking Dojo stage.
+const RULES: &str = "*You have unlimited Speed Ups and Cat CPUs but no other power ups.\n\
+    *The base has unlimited health and can attack Cats that are close to it.\n\
+    *You gain no money for defeating enemies.\n\
+    *Defeating enemies will score points and the number varies.\n\
+    *The total points will decrease overtime.";
+
+/// Maintenance templates at the top of a Ranking Dojo stage's page.
+pub const RANKING_DOJO_MAINTENANCE: &str = "{{LimitedContent}}";
+
+/// Templates and categories at the bottom of a Ranking Dojo stage's page.
+pub const RANKING_DOJO_FOOTER: &str = "{{DojoStages}}\n[[Category:Ranking Dojo Stages]]";
+
+/// Name of the score bonus and the base points it gives for one target, or
+/// [`None`] if the bonus type is unknown.
+fn bonus_info(bonus: &BonusType) -> Option<(&'static str, u32)> {
+    let info = match bonus {
+        BonusType::Weaken([points]) => ("Weaken", *points),
+        BonusType::Knockback([points]) => ("Knockback", *points),
+        BonusType::Strong([points]) => ("Strong", *points),
+        BonusType::Freeze([points]) => ("Freeze", *points),
+        BonusType::MassiveDamage([points]) => ("Massive Damage", *points),
+        BonusType::Slow([points]) => ("Slow", *points),
+        BonusType::Placeholder(id) => {
+            log::warn!("Unknown score bonus type {id}, skipping.");
+            return None;
+        }
+    };
+    Some(info)
+}
+
+/// Table of the points each score bonus gives.
+pub fn bonus_table(bonuses: &[BonusType]) -> String {
+    let mut buf = String::from(
+        "{| class=\"article-table\"\n\
+        ! scope=\"col\" | Bonus\n\
+        ! scope=\"col\" | Points per Enemy",
+    );
+    for (name, points) in bonuses.iter().filter_map(bonus_info) {
+        write!(
+            buf,
+            "\n|-\n| {name}\n| {points}",
+            points = points.to_formatted_string(&Locale::en)
+        )
+        .infallible_write();
+    }
+    buf.write_str("\n|}").infallible_write();
+
+    buf
+}
+
+/// Get the content of the Explanation section for Ranking Dojo stages, which
+/// replaces the Strategy section.
+pub fn explanation(stage: &Stage) -> Option<String> {
+    if stage.id.variant() != T::RankingDojo {
+        return None;
+    }
+
+    let mut buf = String::new();
+    if let Some(limit) = stage.time_limit {
+        let limit = limit.get();
+        writeln!(
+            buf,
+            "*You have {limit} {minutes} to defeat as many enemies as possible.",
+            minutes = plural(limit, "minute", "minutes")
+        )
+        .infallible_write();
+    }
+    buf.write_str(RULES).infallible_write();
+
+    if let Some(bonuses) = &stage.bonuses
+        && !bonuses.bonus_type.is_empty()
+    {
+        write!(buf, "\n\n{}", bonus_table(&bonuses.bonus_type)).infallible_write();
+    }
+
+    Some(buf)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bonuses() {
+        let bonuses = [
+            BonusType::Weaken([100]),
+            BonusType::Placeholder(99),
+            BonusType::MassiveDamage([1_500]),
+        ];
+        assert_eq!(
+            bonus_table(&bonuses),
+            "{| class=\"article-table\"\n\
+            ! scope=\"col\" | Bonus\n\
+            ! scope=\"col\" | Points per Enemy\n\
+            |-\n| Weaken\n| 100\n\
+            |-\n| Massive Damage\n| 1,500\n\
+            |}"
+        );
+    }
+}
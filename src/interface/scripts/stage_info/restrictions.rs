@@ -5,7 +5,10 @@ use crate::{
         map::cached::special_rules::{ContentsType, RuleNameLabel, RuleType, SpecialRule},
         stage::{
             parsed::stage::{Restriction, RestrictionCrowns as Crowns, RestrictionStages, Stage},
-            raw::stage_option::charagroups::{CharaGroup, CharaGroupType},
+            raw::{
+                fixed_formation::FixedUnit,
+                stage_option::charagroups::{CharaGroup, CharaGroupType},
+            },
         },
     },
     interface::error_handler::InfallibleWrite,
@@ -147,6 +150,42 @@ fn get_charagroup_restriction(group: &CharaGroup, simplify: bool) -> String {
     buf
 }
 
+/// Get the `"Fixed lineup: ..."` restriction.
+fn get_fixed_lineup_restriction(units: &[FixedUnit]) -> String {
+    let lineup: Vec<String> = units
+        .iter()
+        .map(|unit| {
+            let form = match unit.form {
+                0 => "Normal Form".to_string(),
+                1 => "Evolved Form".to_string(),
+                2 => "True Form".to_string(),
+                3 => "Ultra Form".to_string(),
+                form => {
+                    log::warn!("Unknown form in fixed lineup: {form}");
+                    format!("Form {form}")
+                }
+            };
+            format!(
+                "{link} ({form}, Lv. {level})",
+                link = CAT_DATA.get_cat_link(unit.id),
+                level = unit.level
+            )
+        })
+        .collect();
+
+    let mut buf = "Fixed lineup: ".to_string();
+    if lineup.len() == 1 {
+        buf.write_str(&lineup[0]).infallible_write();
+    } else {
+        let (last, first) = lineup.split_last().unwrap();
+        let grouped = first.join(", ");
+        buf.write_str(&grouped).infallible_write();
+        buf.write_str(" and ").infallible_write();
+        buf.write_str(last).infallible_write();
+    }
+    buf
+}
+
 /// Get a list of restrictions that a single [Restriction] object corresponds
 /// to.
 fn get_single_restriction(restriction: &Restriction, simplify: bool) -> Vec<String> {
@@ -260,10 +299,10 @@ fn get_multi_restriction(
         .collect()
 }
 
-/// Get a list of stage restrictions if they exist.
+/// Get a list of the restrictions from `Stage_option.csv` if they exist.
 ///
 /// `simplify` uses a compact format for the restrictions.
-fn get_restriction_list(stage: &Stage, simplify: bool) -> Option<Vec<String>> {
+fn get_option_restrictions(stage: &Stage, simplify: bool) -> Option<Vec<String>> {
     let restrictions = stage.restrictions.as_ref()?;
     if restrictions.is_empty() || restrictions == &[FOUR_CROWN_DEFAULT_RESTRICTION] {
         return None;
@@ -299,6 +338,25 @@ fn get_restriction_list(stage: &Stage, simplify: bool) -> Option<Vec<String>> {
     ))
 }
 
+/// Get a list of stage restrictions if they exist, including the stage's fixed
+/// lineup.
+///
+/// `simplify` uses a compact format for the restrictions.
+fn get_restriction_list(stage: &Stage, simplify: bool) -> Option<Vec<String>> {
+    let mut restrictions = get_option_restrictions(stage, simplify).unwrap_or_default();
+    if let Some(units) = &stage.fixed_formation
+        && !units.is_empty()
+    {
+        restrictions.push(get_fixed_lineup_restriction(units));
+    }
+
+    if restrictions.is_empty() {
+        None
+    } else {
+        Some(restrictions)
+    }
+}
+
 /// Get restrictions for Stage Info template (including no continues).
 pub fn restrictions_info(stage: &Stage) -> Option<TemplateParameter> {
     const PARAM_NAME: &str = "restriction";
@@ -318,7 +376,6 @@ pub fn restrictions_info(stage: &Stage) -> Option<TemplateParameter> {
     Some(TemplateParameter::new(PARAM_NAME, buf))
 }
 
-// TODO fixed_formation.csv
 /// Get content of restrictions section.
 pub fn restrictions_section(stage: &Stage) -> Option<String> {
     let restrictions = get_restriction_list(stage, false)?;
@@ -501,6 +558,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn fixed_lineup() {
+        let units = [
+            FixedUnit {
+                id: 0,
+                form: 2,
+                level: 30,
+            },
+            FixedUnit {
+                id: 1,
+                form: 0,
+                level: 20,
+            },
+        ];
+        assert_eq!(
+            get_fixed_lineup_restriction(&units),
+            format!(
+                "Fixed lineup: {cat} (True Form, Lv. 30) and {tank} (Normal Form, Lv. 20)",
+                cat = CAT_DATA.get_cat_link(0),
+                tank = CAT_DATA.get_cat_link(1)
+            )
+        );
+    }
+
+    #[test]
+    fn fixed_lineup_unknown_form() {
+        let units = [FixedUnit {
+            id: 0,
+            form: 4,
+            level: 30,
+        }];
+        assert_eq!(
+            get_fixed_lineup_restriction(&units),
+            format!(
+                "Fixed lineup: {cat} (Form 4, Lv. 30)",
+                cat = CAT_DATA.get_cat_link(0)
+            )
+        );
+    }
+
     #[test]
     fn restriction_rarity_3() {
         let somolon =
@@ -1,21 +1,31 @@
 //! `stage_info` command.
 
 use crate::{
-    game_data::{meta::stage::stage_types::iter_stage_types, stage::parsed::stage::Stage},
+    game_data::{
+        meta::stage::stage_types::{
+            iter_stage_types,
+            parse::parse_range::{is_range_selector, parse_stage_range},
+        },
+        stage::{parsed::stage::Stage, stage_util::get_range_stages},
+    },
     interface::{
         cli::{
             base::BaseOptions,
-            cli_util::{CommandExec, ConfigMerge, input},
+            cli_util::{CommandExec, ConfigMerge, file_names, input, print_json},
             version_opt::VersionOptions,
         },
         config::Config,
-        scripts::stage_info::stage_info::get_stage_info,
+        scripts::stage_info::stage_info::{get_stage_info, get_stage_wiki_data},
+        wiki_api::publish::{PublishOptions, print_or_publish},
     },
+    wikitext::text_utils::extract_link,
 };
 use clap::Args;
 use std::{
     cmp::max,
+    fs,
     io::{self, Write},
+    path::{Path, PathBuf},
 };
 
 fn show_selectors() {
@@ -54,10 +64,20 @@ pub struct StageInfoOptions {
     /// Do you put `|0` in the Magnification template instead of the actual
     /// magnification for gauntlets?
     pub suppress: Option<bool>,
+    #[arg(long)]
+    /// Do you show enemy HP and AP after magnification and crown multipliers?
+    pub effective_stats: Option<bool>,
     #[arg(short, long = "sel")]
     /// Show selector information.
     pub show_sel: bool,
+    #[arg(short, long)]
+    /// Directory to write pages to if the selector matches multiple stages
+    /// (e.g. `sol 0 *` or `event 200 0-5`). Defaults to the current directory.
+    pub output_dir: Option<PathBuf>,
 
+    #[command(flatten)]
+    /// Wiki upload options.
+    pub publish: PublishOptions,
     #[command(flatten)]
     /// Global options.
     pub base: BaseOptions,
@@ -74,8 +94,61 @@ impl ConfigMerge for StageInfoOptions {
         if let Some(suppress) = self.suppress {
             info.set_suppress(suppress);
         }
+        if let Some(effective_stats) = self.effective_stats {
+            info.set_effective_stats(effective_stats);
+        }
     }
 }
+
+impl StageInfoOptions {
+    /// Write a page for every stage that matches the range `selector`.
+    fn exec_range(&self, selector: &str, config: &Config) {
+        let version = config.version.current_version();
+        let range = parse_stage_range(selector)
+            .unwrap_or_else(|e| panic!("Error when getting info for stages {selector:?}: {e}"));
+        let ids = get_range_stages(&range, version);
+        if ids.is_empty() {
+            panic!("No stages found matching {selector:?}.");
+        }
+        assert!(
+            self.publish.page.is_none(),
+            "`--page` can't be used when the selector matches multiple stages."
+        );
+
+        let dir = self.output_dir.as_deref().unwrap_or(Path::new("."));
+        fs::create_dir_all(dir).unwrap_or_else(|e| panic!("Couldn't create {dir:?}: {e}"));
+
+        let wiki_data = ids.iter().map(get_stage_wiki_data).collect::<Vec<_>>();
+        let pages = ids
+            .iter()
+            .zip(&wiki_data)
+            .map(|(id, data)| (extract_link(&data.stage_name.name), id.to_string()))
+            .collect::<Vec<_>>();
+        let extension = if self.base.is_json() { "json" } else { "txt" };
+        let file_names = file_names(&pages, extension);
+
+        for ((id, (title, _)), file_name) in ids.into_iter().zip(pages).zip(file_names) {
+            let stage = Stage::from_id(id, version)
+                .unwrap_or_else(|e| panic!("Error when getting info for stage: {e}"));
+
+            let content = if self.base.is_json() {
+                serde_json::to_string_pretty(&stage).expect("data should always be serialisable")
+            } else {
+                get_stage_info(&stage, config).to_string()
+            };
+
+            if self.publish.publish && !self.base.is_json() {
+                print_or_publish(&self.publish, title, &content, config);
+                continue;
+            }
+
+            let path = dir.join(file_name);
+            fs::write(&path, content).unwrap_or_else(|e| panic!("Couldn't write {path:?}: {e}"));
+            println!("Wrote {path}.", path = path.display());
+        }
+    }
+}
+
 impl CommandExec for StageInfoOptions {
     fn exec(&self, config: &Config) {
         if self.show_sel {
@@ -88,13 +161,24 @@ impl CommandExec for StageInfoOptions {
             0 => &input("Input file selector: "),
             _ => &self.selector.join(" "),
         };
+        if is_range_selector(selector) {
+            self.exec_range(selector, config);
+            return;
+        }
 
         let stage = Stage::from_selector(selector, config.version.current_version());
         let stage = match stage {
             Ok(stage) => stage,
             Err(e) => panic!("Error when getting info for stage {selector:?}: {e}"),
         };
-        println!("{}", get_stage_info(&stage, config));
+        if self.base.is_json() {
+            print_json(&stage);
+            return;
+        }
+        let info = get_stage_info(&stage, config).to_string();
+        let data = get_stage_wiki_data(&stage.id);
+        let title = extract_link(&data.stage_name.name);
+        print_or_publish(&self.publish, title, &info, config);
     }
 }
 
@@ -117,9 +201,12 @@ mod tests {
                 command: Command::StageInfo(StageInfoOptions {
                     selector: ["l 0 0".into()].into(),
                     suppress: Default::default(),
+                    effective_stats: Default::default(),
+                    publish: Default::default(),
                     base: Default::default(),
                     version: Default::default(),
                     show_sel: Default::default(),
+                    output_dir: Default::default(),
                 }),
             }
         );
@@ -140,9 +227,12 @@ mod tests {
                 command: Command::StageInfo(StageInfoOptions {
                     selector: ["l".into(), "0".into(), "0".into()].into(),
                     suppress: Default::default(),
+                    effective_stats: Default::default(),
+                    publish: Default::default(),
                     base: Default::default(),
                     version: Default::default(),
                     show_sel: Default::default(),
+                    output_dir: Default::default(),
                 }),
             }
         );
@@ -163,9 +253,12 @@ mod tests {
                 command: Command::StageInfo(StageInfoOptions {
                     selector: ["filibuster".into()].into(),
                     suppress: Default::default(),
+                    effective_stats: Default::default(),
+                    publish: Default::default(),
                     base: Default::default(),
                     version: Default::default(),
                     show_sel: Default::default(),
+                    output_dir: Default::default(),
                 }),
             }
         );
@@ -176,6 +269,47 @@ mod tests {
         si.exec(&TEST_CONFIG);
     }
 
+    #[test]
+    fn range_selector() {
+        const ARGS: [&str; 6] = ["run_program", "stage", "event", "200", "*", "-o=out"];
+        let cli = Cli::parse_from(ARGS.iter());
+        let Command::StageInfo(si) = cli.command else {
+            unreachable!()
+        };
+        assert_eq!(si.selector, ["event", "200", "*"]);
+        assert_eq!(si.output_dir, Some("out".into()));
+    }
+
+    #[test]
+    fn publish_options() {
+        const ARGS: [&str; 7] = [
+            "run_program",
+            "stage",
+            "l 0 0",
+            "--publish",
+            "--dry-run",
+            "--summary",
+            "Update stats",
+        ];
+        let cli = Cli::parse_from(ARGS.iter());
+        let Command::StageInfo(si) = cli.command else {
+            unreachable!()
+        };
+        assert_eq!(
+            si.publish,
+            PublishOptions {
+                publish: true,
+                page: None,
+                summary: Some("Update stats".into()),
+                bot: false,
+                dry_run: true,
+            }
+        );
+
+        const NO_PUBLISH: [&str; 4] = ["run_program", "stage", "l 0 0", "--dry-run"];
+        assert!(Cli::try_parse_from(NO_PUBLISH.iter()).is_err());
+    }
+
     #[test]
     #[should_panic = "Error when getting info for stage \" 0 0\": unknown variant name"]
     fn invalid_selector() {
@@ -1,19 +1,28 @@
 //! Root module for stage info script.
 
 use crate::{
-    game_data::{meta::stage::stage_id::StageID, stage::parsed::stage::Stage},
+    game_data::{
+        meta::stage::{stage_id::StageID, variant::StageVariantID as T},
+        stage::parsed::stage::Stage,
+        version::lang::VersionLanguage,
+    },
     interface::{
         config::Config,
         scripts::stage_info::{
             battlegrounds::battlegrounds,
             beginning::{enemies_appearing, intro},
+            effective_stats::effective_stats,
             enemies_list::enemies_list,
             information::{
                 base_hp, energy, max_enemies, stage_location, stage_name, time_limit, width, xp,
             },
-            misc_information::{chapter, difficulty, max_clears, stage_nav, star},
+            misc_information::{
+                COLLAB_MAINTENANCE, chapter, collab_footer, difficulty, max_clears, stage_nav, star,
+            },
+            ranking_dojo::{RANKING_DOJO_FOOTER, RANKING_DOJO_MAINTENANCE, explanation},
             restrictions::{restrictions_info, restrictions_section, rules_section},
             treasure::{score_rewards, treasure},
+            zero_legends::{ZERO_LEGENDS_MAINTENANCE, zero_legends_footer},
         },
     },
     wiki_data::stage_wiki_data::{MapWikiData, STAGE_WIKI_DATA, StageWikiData},
@@ -36,20 +45,29 @@ pub fn si_template(
     stage_wiki_data: &StageWikiDataContainer,
     config: &Config,
 ) -> Template {
+    // Ranking Dojo and Zero Legends stages only have Japanese images.
+    let (lang, script) = match stage.id.variant() {
+        T::RankingDojo => (VersionLanguage::JP, "{{subst:SUBPAGENAME}}"),
+        T::ZL => (VersionLanguage::JP, "?"),
+        T::Collab | T::CollabGauntlet => (config.version.lang(), "{{subst:PAGENAME}}"),
+        _ => (config.version.lang(), "?"),
+    };
+    let version = config.version.current_version();
+
     Template::named("Stage Info")
-        .add_params(stage_name(stage, config.version.lang()))
-        .add_params(stage_location(stage, config.version.lang()))
-        .add_params(energy(stage))
+        .add_params(stage_name(stage, lang))
+        .add_params(stage_location(stage, lang))
+        .add_params(energy(stage, version))
         .add_params(base_hp(stage))
         .add_params(enemies_list(stage, config.stage_info.suppress()))
-        .add_params(treasure(stage))
+        .add_params(treasure(stage, version))
         .add_params(restrictions_info(stage))
         .add_params(time_limit(stage))
-        .add_params(score_rewards(stage))
+        .add_params(score_rewards(stage, version))
         .add_params(xp(stage))
         .add_params(width(stage))
         .add_params(max_enemies(stage))
-        .add_const(&[("jpname", "?"), ("script", "?"), ("romaji", "?")])
+        .add_const(&[("jpname", "?"), ("script", script), ("romaji", "?")])
         .add_params(star(stage))
         .add_params(chapter(stage, stage_wiki_data))
         .add_params(max_clears(stage))
@@ -72,6 +90,16 @@ pub fn get_stage_info(stage: &Stage, config: &Config) -> impl Display {
     let mut page = Page::blank();
     let stage_wiki_data = get_stage_wiki_data(&stage.id);
 
+    if matches!(stage.id.variant(), T::Collab | T::CollabGauntlet) {
+        page.push(Section::blank(COLLAB_MAINTENANCE));
+    }
+    if stage.id.variant() == T::RankingDojo {
+        page.push(Section::blank(RANKING_DOJO_MAINTENANCE));
+    }
+    if stage.id.variant() == T::ZL {
+        page.push(Section::blank(ZERO_LEGENDS_MAINTENANCE));
+    }
+
     let appears = enemies_appearing(stage);
     let opener = intro(stage, &stage_wiki_data);
     let intro_sect = Section::blank(appears + "\n" + &opener);
@@ -89,11 +117,28 @@ pub fn get_stage_info(stage: &Stage, config: &Config) -> impl Display {
     }
 
     page.push(Section::h2("Battlegrounds", battlegrounds(stage)));
-    page.push(Section::h2("Strategy", "-"));
+    if config.stage_info.effective_stats()
+        && let Some(s) = effective_stats(stage, config.version.current_version())
+    {
+        page.push(Section::h3("Effective Stats", s));
+    }
+    match explanation(stage) {
+        Some(s) => page.push(Section::h2("Explanation", s)),
+        None => page.push(Section::h2("Strategy", "-")),
+    }
     page.push(Section::h2(
         "Reference",
         "*".to_string() + &reference(stage),
     ));
+    if let Some(footer) = collab_footer(stage, &stage_wiki_data) {
+        page.push(Section::blank(footer));
+    }
+    if stage.id.variant() == T::RankingDojo {
+        page.push(Section::blank(RANKING_DOJO_FOOTER));
+    }
+    if let Some(footer) = zero_legends_footer(&stage.id) {
+        page.push(Section::blank(footer));
+    }
 
     page
 }
@@ -7,6 +7,7 @@ use crate::{
             raw::csv_types::{TreasureCSV, TreasureType as T},
         },
         stage::parsed::stage::{Stage, StageRewards},
+        version::Version,
     },
     interface::error_handler::InfallibleWrite,
     wiki_data::rewards::TREASURE_DATA,
@@ -16,7 +17,7 @@ use num_format::{Locale, WriteFormatted};
 use std::{fmt::Write, num::NonZeroU32};
 
 /// Is the reward a cat unit/true form.
-fn is_unit_drop(id: u32) -> bool {
+pub fn is_unit_drop(id: u32) -> bool {
     (1_000..30_000).contains(&id)
 }
 
@@ -28,20 +29,20 @@ fn drop_limit(id: u32) -> &'static str {
 }
 
 /// Write item name and amount e.g. `50,000 XP` or `Treasure Radar +1`.
-fn write_name_and_amount(buf: &mut String, id: u32, amt: u32) {
+pub fn write_name_and_amount(buf: &mut String, id: u32, amt: u32, version: &Version) {
     if id == 6 {
         // XP is a special case from the rest
         buf.write_formatted(&amt, &Locale::en).infallible_write();
-        write!(buf, " {}", TREASURE_DATA.get_treasure_name(id)).unwrap();
+        write!(buf, " {}", TREASURE_DATA.get_treasure_name(id, version)).unwrap();
         return;
     }
 
     if is_unit_drop(id) {
-        *buf += TREASURE_DATA.get_treasure_name(id);
+        *buf += TREASURE_DATA.get_treasure_name(id, version);
         return;
     }
 
-    write!(buf, "{} +", TREASURE_DATA.get_treasure_name(id)).unwrap();
+    write!(buf, "{} +", TREASURE_DATA.get_treasure_name(id, version)).unwrap();
     buf.write_formatted(&amt, &Locale::en).infallible_write();
 }
 
@@ -51,12 +52,13 @@ fn once_then_unlimited(
     reset_type: ResetType,
     max_clears: Option<NonZeroU32>,
     cooldown: Option<NonZeroU32>,
+    version: &Version,
 ) -> String {
     let mut buf = String::new();
     let t = &rewards.treasure_drop;
 
     buf.write_str("- ").infallible_write();
-    write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt);
+    write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt, version);
 
     let amount = if let Some(num) = max_clears
         && num.get() == 1
@@ -90,7 +92,7 @@ fn once_then_unlimited(
             continue;
         }
         buf.write_str("<br>\n- ").infallible_write();
-        write_name_and_amount(&mut buf, item.item_id, item.item_amt);
+        write_name_and_amount(&mut buf, item.item_id, item.item_amt, version);
 
         let chance = total_allowed * f64::from(item.item_chance) / 100.0;
         total_allowed -= chance;
@@ -102,7 +104,7 @@ fn once_then_unlimited(
 }
 
 /// When treasure type is that all items have unlimited drop potential.
-fn all_unlimited(rewards: &StageRewards) -> String {
+fn all_unlimited(rewards: &StageRewards, version: &Version) -> String {
     let mut buf = String::new();
     let t = &rewards.treasure_drop;
 
@@ -112,7 +114,7 @@ fn all_unlimited(rewards: &StageRewards) -> String {
             continue;
         }
         buf.write_str("- ").infallible_write();
-        write_name_and_amount(&mut buf, item.item_id, item.item_amt);
+        write_name_and_amount(&mut buf, item.item_id, item.item_amt, version);
 
         let chance = total_allowed * f64::from(item.item_chance) / 100.0;
         total_allowed -= chance;
@@ -131,7 +133,7 @@ fn all_unlimited(rewards: &StageRewards) -> String {
 }
 
 /// For the treasure type that appears to be a single raw drop.
-fn single_raw(rewards: &StageRewards) -> String {
+fn single_raw(rewards: &StageRewards, version: &Version) -> String {
     let t = &rewards.treasure_drop;
     assert_eq!(t.len(), 1);
     if t[0].item_chance == 0 {
@@ -140,7 +142,7 @@ fn single_raw(rewards: &StageRewards) -> String {
 
     let mut buf = String::new();
     buf.write_str("- ").infallible_write();
-    write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt);
+    write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt, version);
 
     write!(
         buf,
@@ -171,12 +173,12 @@ fn get_total_chance(treasure: &[TreasureCSV]) -> (bool, f64) {
 
 /// When treasure type is that a treasure is guaranteed but can only be received
 /// once.
-fn guaranteed_once(rewards: &StageRewards) -> String {
+fn guaranteed_once(rewards: &StageRewards, version: &Version) -> String {
     let mut buf = String::new();
     let t = &rewards.treasure_drop;
     if t.len() == 1 {
         buf.write_str("- ").infallible_write();
-        write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt);
+        write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt, version);
         buf.write_str(" (100%, 1 time)").infallible_write();
         return buf;
     }
@@ -187,7 +189,7 @@ fn guaranteed_once(rewards: &StageRewards) -> String {
         .infallible_write();
     for item in t {
         buf.write_str("<br>\n- ").infallible_write();
-        write_name_and_amount(&mut buf, item.item_id, item.item_amt);
+        write_name_and_amount(&mut buf, item.item_id, item.item_amt, version);
         if !is_equal_chance {
             let item_chance = f64::from(100 * item.item_chance) / total;
             let chance = get_formatted_float(item_chance, 1);
@@ -200,13 +202,13 @@ fn guaranteed_once(rewards: &StageRewards) -> String {
 
 /// When treasure type is that a treasure is guaranteed and the stage has
 /// unlimited drops.
-fn guaranteed_unlimited(rewards: &StageRewards) -> String {
+fn guaranteed_unlimited(rewards: &StageRewards, version: &Version) -> String {
     let mut buf = String::new();
     let t = &rewards.treasure_drop;
 
     if t.len() == 1 {
         buf.write_str("- ").infallible_write();
-        write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt);
+        write_name_and_amount(&mut buf, t[0].item_id, t[0].item_amt, version);
         buf.write_str(" (100%, unlimited)").infallible_write();
         return buf;
     }
@@ -217,7 +219,7 @@ fn guaranteed_unlimited(rewards: &StageRewards) -> String {
         .infallible_write();
     for item in t {
         buf.write_str("<br>\n- ").infallible_write();
-        write_name_and_amount(&mut buf, item.item_id, item.item_amt);
+        write_name_and_amount(&mut buf, item.item_id, item.item_amt, version);
         if !is_equal_chance {
             let item_chance = f64::from(100 * item.item_chance) / total;
             let chance = get_formatted_float(item_chance, 1);
@@ -229,17 +231,21 @@ fn guaranteed_unlimited(rewards: &StageRewards) -> String {
 }
 
 /// Get the `treasure` section of Stage Info.
-pub fn treasure(stage: &Stage) -> Option<TemplateParameter> {
+pub fn treasure(stage: &Stage, version: &Version) -> Option<TemplateParameter> {
     let rewards = stage.rewards.as_ref()?;
 
     let treasure_text = match rewards.treasure_type {
-        T::OnceThenUnlimited => {
-            once_then_unlimited(rewards, stage.reset_type, stage.max_clears, stage.cooldown)
-        }
-        T::AllUnlimited => all_unlimited(rewards),
-        T::UnclearMaybeRaw => single_raw(rewards),
-        T::GuaranteedOnce => guaranteed_once(rewards),
-        T::GuaranteedUnlimited => guaranteed_unlimited(rewards),
+        T::OnceThenUnlimited => once_then_unlimited(
+            rewards,
+            stage.reset_type,
+            stage.max_clears,
+            stage.cooldown,
+            version,
+        ),
+        T::AllUnlimited => all_unlimited(rewards, version),
+        T::UnclearMaybeRaw => single_raw(rewards, version),
+        T::GuaranteedOnce => guaranteed_once(rewards, version),
+        T::GuaranteedUnlimited => guaranteed_unlimited(rewards, version),
     };
 
     if treasure_text.is_empty() {
@@ -250,7 +256,7 @@ pub fn treasure(stage: &Stage) -> Option<TemplateParameter> {
 }
 
 /// Get the `score reward` section of Stage Info.
-pub fn score_rewards(stage: &Stage) -> Option<TemplateParameter> {
+pub fn score_rewards(stage: &Stage, version: &Version) -> Option<TemplateParameter> {
     let rewards = &stage.rewards.as_ref()?.score_rewards;
     if rewards.is_empty() {
         return None;
@@ -264,7 +270,7 @@ pub fn score_rewards(stage: &Stage) -> Option<TemplateParameter> {
             buf.write_formatted(&r.score, &Locale::en)
                 .infallible_write();
             buf.write_str("''': ").infallible_write();
-            write_name_and_amount(&mut buf, r.item_id, r.item_amt);
+            write_name_and_amount(&mut buf, r.item_id, r.item_amt, version);
             buf
         })
         .collect::<Vec<String>>()
@@ -276,16 +282,24 @@ pub fn score_rewards(stage: &Stage) -> Option<TemplateParameter> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::game_data::{
-        map::raw::csv_types::{TreasureCSV, TreasureType},
-        meta::stage::{stage_id::StageID, variant::StageVariantID},
+    use crate::{
+        TEST_CONFIG,
+        game_data::{
+            map::raw::csv_types::{TreasureCSV, TreasureType},
+            meta::stage::{stage_id::StageID, variant::StageVariantID},
+        },
     };
 
     #[test]
     fn write_name_and_amount_normal() {
         const CAT_FOOD: u32 = 13;
         let mut buf = String::new();
-        write_name_and_amount(&mut buf, CAT_FOOD, 22_222);
+        write_name_and_amount(
+            &mut buf,
+            CAT_FOOD,
+            22_222,
+            TEST_CONFIG.version.current_version(),
+        );
         assert_eq!(buf, "[[Cat Food]] +22,222");
     }
 
@@ -293,7 +307,7 @@ mod tests {
     fn write_name_and_amount_xp() {
         const XP: u32 = 6;
         let mut buf = String::new();
-        write_name_and_amount(&mut buf, XP, 40_000);
+        write_name_and_amount(&mut buf, XP, 40_000, TEST_CONFIG.version.current_version());
         assert_eq!(buf, "40,000 XP");
     }
 
@@ -301,7 +315,12 @@ mod tests {
     fn write_name_and_amount_unit() {
         const CRAZED_CAT: u32 = 1_103;
         let mut buf = String::new();
-        write_name_and_amount(&mut buf, CRAZED_CAT, 40_000);
+        write_name_and_amount(
+            &mut buf,
+            CRAZED_CAT,
+            40_000,
+            TEST_CONFIG.version.current_version(),
+        );
         assert_eq!(buf, "[[Crazed Cat (Super Rare Cat)|Crazed Cat]]");
     }
 
@@ -309,7 +328,12 @@ mod tests {
     fn write_name_and_amount_tf() {
         const MANIC_MOHAWK: u32 = 10_092;
         let mut buf = String::new();
-        write_name_and_amount(&mut buf, MANIC_MOHAWK, 40_000);
+        write_name_and_amount(
+            &mut buf,
+            MANIC_MOHAWK,
+            40_000,
+            TEST_CONFIG.version.current_version(),
+        );
         assert_eq!(
             buf,
             "[[Crazed Cat (Super Rare Cat)|Crazed Cat]]'s [[True Form]]"
@@ -320,7 +344,12 @@ mod tests {
     fn write_name_and_amount_orb() {
         const RED_ATTACK_ORB: u32 = 30_000;
         let mut buf = String::new();
-        write_name_and_amount(&mut buf, RED_ATTACK_ORB, 1);
+        write_name_and_amount(
+            &mut buf,
+            RED_ATTACK_ORB,
+            1,
+            TEST_CONFIG.version.current_version(),
+        );
         assert_eq!(buf, "Attack Up D [[Talent Orbs|Orb]]: Red +1");
     }
 
@@ -329,13 +358,16 @@ mod tests {
         let ht30 =
             Stage::from_id_current(StageID::from_components(StageVariantID::Tower, 0, 29)).unwrap();
         assert_eq!(
-            treasure(&ht30),
+            treasure(&ht30, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Cat Capsule#Rare Cat Capsule|Rare Ticket]] +3 (100%, 1 time)"
             ))
         );
-        assert_eq!(score_rewards(&ht30), None);
+        assert_eq!(
+            score_rewards(&ht30, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -343,13 +375,16 @@ mod tests {
         let dark_souls =
             Stage::from_id_current(StageID::from_components(StageVariantID::Event, 17, 0)).unwrap();
         assert_eq!(
-            treasure(&dark_souls),
+            treasure(&dark_souls, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Crazed Cat (Super Rare Cat)|Crazed Cat]] (100%, 1 time)"
             ))
         );
-        assert_eq!(score_rewards(&dark_souls), None);
+        assert_eq!(
+            score_rewards(&dark_souls, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -358,7 +393,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Event, 184, 4))
                 .unwrap();
         assert_eq!(
-            treasure(&new_year_finale),
+            treasure(&new_year_finale, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Cat Capsule#Lucky Capsule G|Lucky Ticket G]] +3 (100%, 1 time)<br>\n\
@@ -366,7 +401,10 @@ mod tests {
                 - [[Cat Capsule#Lucky Capsule G|Lucky Ticket G]] +1 (70%, unlimited)"
             ))
         );
-        assert_eq!(score_rewards(&new_year_finale), None);
+        assert_eq!(
+            score_rewards(&new_year_finale, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -375,7 +413,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Event, 155, 0))
                 .unwrap();
         assert_eq!(
-            treasure(&merciless_xp),
+            treasure(&merciless_xp, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- 2,030,000 XP (10%, unlimited{{TreasureAdjustment}})<br>\n\
@@ -383,7 +421,10 @@ mod tests {
                 - 510,000 XP (70%, unlimited)"
             ))
         );
-        assert_eq!(score_rewards(&merciless_xp), None);
+        assert_eq!(
+            score_rewards(&merciless_xp, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -391,7 +432,7 @@ mod tests {
         let jubilee_night =
             Stage::from_id_current(StageID::from_components(StageVariantID::Extra, 1, 0)).unwrap();
         assert_eq!(
-            treasure(&jubilee_night),
+            treasure(&jubilee_night, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Catfruit|Epic Catfruit]] +1 (70%, unlimited)<br>\n\
@@ -399,7 +440,10 @@ mod tests {
                 - [[Catfruit|Purple Catfruit Seed]] +1 (4.5%, unlimited)"
             ))
         );
-        assert_eq!(score_rewards(&jubilee_night), None);
+        assert_eq!(
+            score_rewards(&jubilee_night, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -419,7 +463,10 @@ mod tests {
                 score_rewards: vec![]
             })
         );
-        assert_eq!(treasure(&round_4_trust_fund), None);
+        assert_eq!(
+            treasure(&round_4_trust_fund, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -427,13 +474,16 @@ mod tests {
         let it30 =
             Stage::from_id_current(StageID::from_components(StageVariantID::Tower, 6, 29)).unwrap();
         assert_eq!(
-            treasure(&it30),
+            treasure(&it30, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Catfruit|Gold Catfruit Seed]] +1 (100%, 1 time)"
             ))
         );
-        assert_eq!(score_rewards(&it30), None);
+        assert_eq!(
+            score_rewards(&it30, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -441,7 +491,7 @@ mod tests {
         let it29 =
             Stage::from_id_current(StageID::from_components(StageVariantID::Tower, 6, 28)).unwrap();
         assert_eq!(
-            treasure(&it29),
+            treasure(&it29, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "One of the following (1 time):<br>\n\
@@ -455,7 +505,10 @@ mod tests {
                 - Ammonite +5 (9%)"
             ))
         );
-        assert_eq!(score_rewards(&it29), None);
+        assert_eq!(
+            score_rewards(&it29, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -463,7 +516,7 @@ mod tests {
         let it2 =
             Stage::from_id_current(StageID::from_components(StageVariantID::Tower, 6, 1)).unwrap();
         assert_eq!(
-            treasure(&it2),
+            treasure(&it2, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "One of the following (1 time):<br>\n\
@@ -480,7 +533,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Collab, 128, 0))
                 .unwrap();
         assert_eq!(
-            treasure(&spring_popstar),
+            treasure(&spring_popstar, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "One of the following (unlimited):<br>\n\
@@ -498,7 +551,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Enigma, 30, 0))
                 .unwrap();
         assert_eq!(
-            treasure(&afternoon_bug_hunt),
+            treasure(&afternoon_bug_hunt, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Summer Break Cats (Event Gacha)|Legend Net]] +1 (100%, unlimited)"
@@ -512,7 +565,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Collab, 128, 2))
                 .unwrap();
         assert_eq!(
-            treasure(&sakura_dance),
+            treasure(&sakura_dance, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "One of the following (unlimited):<br>\n\
@@ -524,7 +577,10 @@ mod tests {
                 - [[Battle Items#Sniper the Cat|Sniper the Cat]] +2"
             ))
         );
-        assert_eq!(score_rewards(&sakura_dance), None);
+        assert_eq!(
+            score_rewards(&sakura_dance, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -533,8 +589,14 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Labyrinth, 0, 66))
                 .unwrap();
         assert_eq!(labyrinth_67.rewards, None);
-        assert_eq!(treasure(&labyrinth_67), None);
-        assert_eq!(score_rewards(&labyrinth_67), None);
+        assert_eq!(
+            treasure(&labyrinth_67, TEST_CONFIG.version.current_version()),
+            None
+        );
+        assert_eq!(
+            score_rewards(&labyrinth_67, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -543,7 +605,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::MainChapters, 3, 1))
                 .unwrap();
         assert_eq!(
-            score_rewards(&korea),
+            score_rewards(&korea, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "score reward",
                 "'''8,500''': [[Cat Food]] +10<br>\n\
@@ -569,7 +631,10 @@ mod tests {
                 score_rewards: vec![]
             })
         );
-        assert_eq!(treasure(&explosion_in_sky), None);
+        assert_eq!(
+            treasure(&explosion_in_sky, TEST_CONFIG.version.current_version()),
+            None
+        );
     }
 
     #[test]
@@ -578,7 +643,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Event, 150, 0))
                 .unwrap();
         assert_eq!(
-            treasure(&impact_site),
+            treasure(&impact_site, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "One of the following (unlimited):<br>\n\
@@ -596,7 +661,7 @@ mod tests {
             Stage::from_id_current(StageID::from_components(StageVariantID::Collab, 102, 0))
                 .unwrap();
         assert_eq!(
-            treasure(&not_fault),
+            treasure(&not_fault, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Shinji & Cat (Rare Cat)|Shinji & Cat]]'s [[True Form]] (5%, 1 time)"
@@ -621,7 +686,7 @@ mod tests {
             })
         );
         assert_eq!(
-            treasure(&incompetent_watchman),
+            treasure(&incompetent_watchman, TEST_CONFIG.version.current_version()),
             Some(TemplateParameter::new(
                 "treasure",
                 "- [[Battle Items#Sniper the Cat|Sniper the Cat]] +1 (1%, unlimited)"
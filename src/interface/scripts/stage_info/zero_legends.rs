@@ -0,0 +1,45 @@
+//! Deals with the extra templates and categories of Zero Legends stages.
+
+use crate::game_data::meta::stage::{stage_id::StageID, variant::StageVariantID as T};
+
+/// Maintenance templates at the top of a Zero Legends stage's page.
+pub const ZERO_LEGENDS_MAINTENANCE: &str = "{{LimitedContent}}\n{{StrategyNeeded}}";
+
+/// Amount of Stories of Legend and Uncanny Legends sub-chapters before the
+/// first Zero Legends sub-chapter.
+const PREVIOUS_SUB_CHAPTERS: u32 = 99;
+
+/// Templates and categories at the bottom of a Zero Legends stage's page.
+pub fn zero_legends_footer(id: &StageID) -> Option<String> {
+    if id.variant() != T::ZL {
+        return None;
+    }
+
+    Some(format!(
+        "{{{{LegendStages}}}}\n\
+        [[Category:Sub-chapter {num} Stages]]\n\
+        [[Category:Zero Legends Stages]]",
+        num = id.map().num() + PREVIOUS_SUB_CHAPTERS
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn footer() {
+        assert_eq!(
+            zero_legends_footer(&StageID::from_components(T::ZL, 2, 0)).as_deref(),
+            Some(
+                "{{LegendStages}}\n\
+                [[Category:Sub-chapter 101 Stages]]\n\
+                [[Category:Zero Legends Stages]]"
+            )
+        );
+        assert_eq!(
+            zero_legends_footer(&StageID::from_components(T::UL, 2, 0)),
+            None
+        );
+    }
+}
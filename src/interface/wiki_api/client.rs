@@ -0,0 +1,340 @@
+//! Client for the MediaWiki action API.
+
+use crate::interface::config::wiki_config::WikiConfig;
+use serde_json::Value;
+use ureq::{Agent, Body, http::Response};
+
+#[derive(Debug, thiserror::Error)]
+/// Error when using the API.
+pub enum WikiApiError {
+    /// Request failed.
+    #[error("request failed: {0}")]
+    Http(#[from] ureq::Error),
+    /// Response wasn't valid JSON.
+    #[error("couldn't parse API response: {0}")]
+    Json(#[from] serde_json::Error),
+    /// API returned an error.
+    #[error("API error {code:?}: {info}")]
+    Api {
+        /// Error code.
+        code: String,
+        /// Error description.
+        info: String,
+    },
+    /// Login didn't succeed.
+    #[error("login failed: {0}")]
+    Login(String),
+    /// Edit didn't succeed.
+    #[error("edit failed: {0}")]
+    Edit(String),
+    /// Response didn't contain an expected value.
+    #[error("unexpected API response: missing {0}")]
+    Unexpected(&'static str),
+}
+
+#[derive(Debug, Default)]
+/// Cookies set by the wiki, used to keep the login session.
+struct CookieJar(Vec<(String, String)>);
+impl CookieJar {
+    /// Store all cookies set by `response`.
+    fn update(&mut self, response: &Response<Body>) {
+        for header in response.headers().get_all("set-cookie") {
+            let Ok(header) = header.to_str() else {
+                continue;
+            };
+            let pair = header.split(';').next().unwrap_or_default();
+            let Some((name, value)) = pair.split_once('=') else {
+                continue;
+            };
+            let (name, value) = (name.trim(), value.trim());
+
+            match self.0.iter_mut().find(|(n, _)| n == name) {
+                Some(cookie) => cookie.1 = value.to_string(),
+                None => self.0.push((name.to_string(), value.to_string())),
+            }
+        }
+    }
+
+    /// Value of the `Cookie` header.
+    fn header(&self) -> String {
+        self.0
+            .iter()
+            .map(|(name, value)| format!("{name}={value}"))
+            .collect::<Vec<_>>()
+            .join("; ")
+    }
+}
+
+#[derive(Debug)]
+/// Single page edit.
+pub struct Edit<'a> {
+    /// Title of the page.
+    pub title: &'a str,
+    /// New content of the page.
+    pub text: &'a str,
+    /// Edit summary.
+    pub summary: &'a str,
+    /// Mark the edit as a bot edit.
+    pub bot: bool,
+    /// Do everything except actually make the edit.
+    pub dry_run: bool,
+}
+
+#[derive(Debug, PartialEq)]
+/// Result of a successful edit.
+pub enum EditResult {
+    /// Page was edited.
+    Edited {
+        /// New revision id.
+        revid: u64,
+    },
+    /// Page content was the same, so nothing happened.
+    NoChange,
+    /// Edit was a dry run.
+    DryRun,
+}
+
+/// Client that can log in and edit pages.
+pub struct WikiClient {
+    agent: Agent,
+    api_url: String,
+    user_agent: String,
+    cookies: CookieJar,
+}
+impl WikiClient {
+    /// Create new client for the API at `api_url`.
+    pub fn new<T: Into<String>, U: Into<String>>(api_url: T, user_agent: U) -> Self {
+        Self {
+            agent: Agent::new_with_defaults(),
+            api_url: api_url.into(),
+            user_agent: user_agent.into(),
+            cookies: CookieJar::default(),
+        }
+    }
+
+    /// Create new client from the user's config.
+    pub fn from_config(config: &WikiConfig) -> Self {
+        let user_agent = format!("{}/rust-wiki-reader", config.username);
+        Self::new(config.api_url(), user_agent)
+    }
+
+    /// Read the API response, returning an error if the API returned one.
+    fn read_response(&mut self, response: Response<Body>) -> Result<Value, WikiApiError> {
+        self.cookies.update(&response);
+        let json: Value = serde_json::from_str(&response.into_body().read_to_string()?)?;
+
+        if let Some(error) = json.get("error") {
+            let field = |key| error[key].as_str().unwrap_or_default().to_string();
+            return Err(WikiApiError::Api {
+                code: field("code"),
+                info: field("info"),
+            });
+        }
+
+        Ok(json)
+    }
+
+    /// Make a GET request to the API.
+    fn get(&mut self, params: &[(&str, &str)]) -> Result<Value, WikiApiError> {
+        let response = self
+            .agent
+            .get(&self.api_url)
+            .header("user-agent", &self.user_agent)
+            .header("cookie", &self.cookies.header())
+            .query_pairs(params.iter().copied().chain([("format", "json")]))
+            .call()?;
+        self.read_response(response)
+    }
+
+    /// Make a POST request to the API.
+    fn post(&mut self, params: &[(&str, &str)]) -> Result<Value, WikiApiError> {
+        let response = self
+            .agent
+            .post(&self.api_url)
+            .header("user-agent", &self.user_agent)
+            .header("cookie", &self.cookies.header())
+            .send_form(params.iter().copied().chain([("format", "json")]))?;
+        self.read_response(response)
+    }
+
+    /// Get a token of type `token_type` (e.g. `"login"` or `"csrf"`).
+    fn token(&mut self, token_type: &str) -> Result<String, WikiApiError> {
+        let json = self.get(&[
+            ("action", "query"),
+            ("meta", "tokens"),
+            ("type", token_type),
+        ])?;
+        json["query"]["tokens"][format!("{token_type}token")]
+            .as_str()
+            .map(String::from)
+            .ok_or(WikiApiError::Unexpected("token"))
+    }
+
+    /// Log in with `name` and `password`.
+    pub fn login(&mut self, name: &str, password: &str) -> Result<(), WikiApiError> {
+        let token = self.token("login")?;
+        let json = self.post(&[
+            ("action", "login"),
+            ("lgname", name),
+            ("lgpassword", password),
+            ("lgtoken", &token),
+        ])?;
+
+        match json["login"]["result"].as_str() {
+            Some("Success") => Ok(()),
+            Some(result) => {
+                let reason = json["login"]["reason"].as_str().unwrap_or(result);
+                Err(WikiApiError::Login(reason.to_string()))
+            }
+            None => Err(WikiApiError::Unexpected("login result")),
+        }
+    }
+
+    /// Edit a page. Need to [login][WikiClient::login] first.
+    pub fn edit(&mut self, edit: &Edit) -> Result<EditResult, WikiApiError> {
+        let token = self.token("csrf")?;
+        if edit.dry_run {
+            return Ok(EditResult::DryRun);
+        }
+
+        let mut params = vec![
+            ("action", "edit"),
+            ("title", edit.title),
+            ("text", edit.text),
+            ("summary", edit.summary),
+        ];
+        if edit.bot {
+            params.push(("bot", "1"));
+        }
+        // token should always be last so that truncated requests fail
+        params.push(("token", &token));
+        let json = self.post(&params)?;
+
+        let result = &json["edit"];
+        match result["result"].as_str() {
+            Some("Success") if result.get("nochange").is_some() => Ok(EditResult::NoChange),
+            Some("Success") => Ok(EditResult::Edited {
+                revid: result["newrevid"].as_u64().unwrap_or_default(),
+            }),
+            Some(other) => Err(WikiApiError::Edit(other.to_string())),
+            None => Err(WikiApiError::Unexpected("edit result")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::wiki_api::mock_server::{MockResponse, MockServer};
+
+    const LOGIN_TOKEN: &str = "login+\\";
+    const CSRF_TOKEN: &str = "csrf+\\";
+
+    /// Very small stand-in for a MediaWiki API.
+    fn mock_mediawiki() -> MockServer {
+        MockServer::start(|request| {
+            let logged_in = request
+                .header("cookie")
+                .is_some_and(|c| c.contains("session=1"));
+            let param = |key| request.param(key).unwrap_or_default();
+
+            match param("action").as_str() {
+                "query" if param("type") == "login" => {
+                    MockResponse::json(r#"{"query":{"tokens":{"logintoken":"login+\\"}}}"#)
+                }
+                "query" if logged_in => {
+                    MockResponse::json(r#"{"query":{"tokens":{"csrftoken":"csrf+\\"}}}"#)
+                }
+                "query" => MockResponse::json(r#"{"query":{"tokens":{"csrftoken":"+\\"}}}"#),
+                "login" if param("lgtoken") != LOGIN_TOKEN => {
+                    MockResponse::json(r#"{"login":{"result":"Failed","reason":"bad token"}}"#)
+                }
+                "login" if param("lgpassword") == "password" => {
+                    MockResponse::json(r#"{"login":{"result":"Success"}}"#)
+                        .with_header("set-cookie", "session=1; path=/; HttpOnly")
+                }
+                "login" => MockResponse::json(
+                    r#"{"login":{"result":"Failed","reason":"Incorrect password"}}"#,
+                ),
+                "edit" if param("token") != CSRF_TOKEN => MockResponse::json(
+                    r#"{"error":{"code":"badtoken","info":"Invalid CSRF token."}}"#,
+                ),
+                "edit" if param("text") == "same" => {
+                    MockResponse::json(r#"{"edit":{"result":"Success","nochange":""}}"#)
+                }
+                "edit" => MockResponse::json(r#"{"edit":{"result":"Success","newrevid":42}}"#),
+                _ => MockResponse::json(r#"{"error":{"code":"badvalue","info":"?"}}"#),
+            }
+        })
+    }
+
+    fn edit<'a>(text: &'a str, dry_run: bool) -> Edit<'a> {
+        Edit {
+            title: "Sandbox",
+            text,
+            summary: "test",
+            bot: true,
+            dry_run,
+        }
+    }
+
+    #[test]
+    fn login_and_edit() {
+        let server = mock_mediawiki();
+        let mut client = WikiClient::new(server.url(), "test");
+
+        client.login("User@bot", "password").unwrap();
+        let result = client.edit(&edit("new content", false)).unwrap();
+        assert_eq!(result, EditResult::Edited { revid: 42 });
+
+        let requests = server.requests();
+        let edit_req = requests.last().unwrap();
+        assert_eq!(edit_req.method, "POST");
+        assert_eq!(edit_req.param("text").unwrap(), "new content");
+        assert_eq!(edit_req.param("bot").unwrap(), "1");
+        assert_eq!(edit_req.param("format").unwrap(), "json");
+    }
+
+    #[test]
+    fn no_change() {
+        let server = mock_mediawiki();
+        let mut client = WikiClient::new(server.url(), "test");
+        client.login("User@bot", "password").unwrap();
+        assert_eq!(
+            client.edit(&edit("same", false)).unwrap(),
+            EditResult::NoChange
+        );
+    }
+
+    #[test]
+    fn dry_run() {
+        let server = mock_mediawiki();
+        let mut client = WikiClient::new(server.url(), "test");
+        client.login("User@bot", "password").unwrap();
+
+        let result = client.edit(&edit("new content", true)).unwrap();
+        assert_eq!(result, EditResult::DryRun);
+        assert!(
+            server
+                .requests()
+                .iter()
+                .all(|r| r.param("action").unwrap() != "edit")
+        );
+    }
+
+    #[test]
+    fn wrong_password() {
+        let server = mock_mediawiki();
+        let mut client = WikiClient::new(server.url(), "test");
+        let err = client.login("User@bot", "hunter2").unwrap_err();
+        assert!(matches!(err, WikiApiError::Login(reason) if reason == "Incorrect password"));
+    }
+
+    #[test]
+    fn not_logged_in() {
+        let server = mock_mediawiki();
+        let mut client = WikiClient::new(server.url(), "test");
+        let err = client.edit(&edit("new content", false)).unwrap_err();
+        assert!(matches!(err, WikiApiError::Api { code, .. } if code == "badtoken"));
+    }
+}
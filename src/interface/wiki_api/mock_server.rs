@@ -0,0 +1,213 @@
+//! Local stand-in HTTP server, so API code can be tested without the wiki.
+
+use std::{
+    io::{BufRead, BufReader, Read, Write},
+    net::{TcpListener, TcpStream},
+    sync::{Arc, Mutex},
+    thread,
+};
+
+#[derive(Debug, Clone)]
+/// Request received by the server.
+pub struct MockRequest {
+    /// HTTP method.
+    pub method: String,
+    /// Path of the request, without the query string.
+    pub path: String,
+    /// Headers, with lowercase names.
+    pub headers: Vec<(String, String)>,
+    /// Decoded parameters from both the query string and a form body.
+    pub params: Vec<(String, String)>,
+}
+impl MockRequest {
+    /// Get value of header `name`.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(n, _)| n.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Get value of parameter `key`.
+    pub fn param(&self, key: &str) -> Option<String> {
+        self.params
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+    }
+}
+
+#[derive(Debug)]
+/// Response the server sends back.
+pub struct MockResponse {
+    status: u16,
+    headers: Vec<(String, String)>,
+    body: String,
+}
+impl MockResponse {
+    /// 200 response with a JSON body.
+    pub fn json<T: Into<String>>(body: T) -> Self {
+        Self {
+            status: 200,
+            headers: vec![("content-type".into(), "application/json".into())],
+            body: body.into(),
+        }
+    }
+
+    /// Add a header to the response.
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+}
+
+/// Decode a `application/x-www-form-urlencoded` value.
+fn url_decode(value: &str) -> String {
+    let mut bytes = vec![];
+    let mut iter = value.bytes();
+    while let Some(b) = iter.next() {
+        match b {
+            b'+' => bytes.push(b' '),
+            b'%' => {
+                let hex = [iter.next().unwrap_or(b'0'), iter.next().unwrap_or(b'0')];
+                let hex = std::str::from_utf8(&hex).unwrap_or("00");
+                bytes.push(u8::from_str_radix(hex, 16).unwrap_or_default());
+            }
+            b => bytes.push(b),
+        }
+    }
+    String::from_utf8_lossy(&bytes).into_owned()
+}
+
+/// Parse `a=b&c=d`.
+fn parse_params(params: &str) -> impl Iterator<Item = (String, String)> + '_ {
+    params.split('&').filter(|p| !p.is_empty()).map(|pair| {
+        let (k, v) = pair.split_once('=').unwrap_or((pair, ""));
+        (url_decode(k), url_decode(v))
+    })
+}
+
+/// Read a single request from the connection. Returns [`None`] if the
+/// connection has been closed.
+fn read_request(reader: &mut BufReader<TcpStream>) -> Option<MockRequest> {
+    let mut line = String::new();
+    if reader.read_line(&mut line).ok()? == 0 {
+        return None;
+    }
+    let mut parts = line.split_whitespace();
+    let method = parts.next()?.to_string();
+    let target = parts.next()?;
+    let (path, query) = target.split_once('?').unwrap_or((target, ""));
+
+    let mut headers = vec![];
+    loop {
+        let mut line = String::new();
+        reader.read_line(&mut line).ok()?;
+        let line = line.trim_end();
+        if line.is_empty() {
+            break;
+        }
+        let (name, value) = line.split_once(':')?;
+        headers.push((name.trim().to_lowercase(), value.trim().to_string()));
+    }
+
+    let mut params = parse_params(query).collect::<Vec<_>>();
+    let length = headers
+        .iter()
+        .find(|(n, _)| n == "content-length")
+        .and_then(|(_, v)| v.parse().ok())
+        .unwrap_or(0);
+    if length > 0 {
+        let mut body = vec![0; length];
+        reader.read_exact(&mut body).ok()?;
+        params.extend(parse_params(&String::from_utf8_lossy(&body)));
+    }
+
+    Some(MockRequest {
+        method,
+        path: path.to_string(),
+        headers,
+        params,
+    })
+}
+
+fn write_response(stream: &mut TcpStream, response: &MockResponse) -> std::io::Result<()> {
+    let mut buf = format!("HTTP/1.1 {} Mock\r\n", response.status);
+    for (name, value) in &response.headers {
+        buf += &format!("{name}: {value}\r\n");
+    }
+    buf += &format!(
+        "content-length: {len}\r\n\r\n{body}",
+        len = response.body.len(),
+        body = response.body
+    );
+    stream.write_all(buf.as_bytes())?;
+    stream.flush()
+}
+
+type Handler = dyn Fn(&MockRequest) -> MockResponse + Send + Sync;
+
+/// HTTP server running on localhost in a background thread.
+pub struct MockServer {
+    port: u16,
+    requests: Arc<Mutex<Vec<MockRequest>>>,
+}
+impl MockServer {
+    /// Start a server where every request is answered by `handler`.
+    pub fn start<F>(handler: F) -> Self
+    where
+        F: Fn(&MockRequest) -> MockResponse + Send + Sync + 'static,
+    {
+        let listener = TcpListener::bind("127.0.0.1:0").expect("couldn't bind mock server");
+        let port = listener.local_addr().unwrap().port();
+        let requests = Arc::new(Mutex::new(vec![]));
+        let handler: Arc<Handler> = Arc::new(handler);
+
+        let reqs = Arc::clone(&requests);
+        thread::spawn(move || {
+            for stream in listener.incoming() {
+                let Ok(mut stream) = stream else {
+                    continue;
+                };
+                let handler = Arc::clone(&handler);
+                let reqs = Arc::clone(&reqs);
+                thread::spawn(move || {
+                    let mut reader = BufReader::new(stream.try_clone().unwrap());
+                    while let Some(request) = read_request(&mut reader) {
+                        let response = handler(&request);
+                        reqs.lock().unwrap().push(request);
+                        if write_response(&mut stream, &response).is_err() {
+                            break;
+                        }
+                    }
+                });
+            }
+        });
+
+        Self { port, requests }
+    }
+
+    /// URL of the server's API endpoint.
+    pub fn url(&self) -> String {
+        format!("http://127.0.0.1:{port}/w/api.php", port = self.port)
+    }
+
+    /// All requests received so far.
+    pub fn requests(&self) -> Vec<MockRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode() {
+        assert_eq!(url_decode("a+b%2Bc%5C"), "a b+c\\");
+        assert_eq!(
+            parse_params("x=1&y=%26").collect::<Vec<_>>(),
+            [("x".into(), "1".into()), ("y".into(), "&".into())]
+        );
+    }
+}
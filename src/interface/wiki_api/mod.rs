@@ -0,0 +1,6 @@
+//! Interact with the wiki's MediaWiki API.
+
+pub mod client;
+#[cfg(test)]
+pub mod mock_server;
+pub mod publish;
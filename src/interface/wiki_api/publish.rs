@@ -0,0 +1,124 @@
+//! Publish command output to the wiki.
+
+use super::client::{Edit, EditResult, WikiApiError, WikiClient};
+use crate::interface::config::Config;
+use clap::Args;
+
+/// Edit summary used if none is given.
+const DEFAULT_SUMMARY: &str = "Update page with rust-wiki";
+
+#[derive(Debug, Default, Args, PartialEq)]
+/// Options for uploading output to the wiki.
+pub struct PublishOptions {
+    #[arg(long)]
+    /// Upload the output to the wiki instead of printing it.
+    pub publish: bool,
+    #[arg(long, requires = "publish")]
+    /// Title of the page to edit. Defaults to the page the output is for.
+    pub page: Option<String>,
+    #[arg(long, requires = "publish")]
+    /// Edit summary.
+    pub summary: Option<String>,
+    #[arg(long, requires = "publish")]
+    /// Mark the edit as a bot edit.
+    pub bot: bool,
+    #[arg(long, requires = "publish")]
+    /// Log in and get an edit token but don't make the edit.
+    pub dry_run: bool,
+}
+
+/// Upload `text` to the page `title` using the user's credentials.
+pub fn upload(
+    options: &PublishOptions,
+    title: &str,
+    text: &str,
+    config: &Config,
+) -> Result<EditResult, WikiApiError> {
+    let Some(password) = &config.wiki.bot_password else {
+        return Err(WikiApiError::Login(
+            "no `bot_password` set in the `wiki` section of the config".to_string(),
+        ));
+    };
+
+    let mut client = WikiClient::from_config(&config.wiki);
+    client.login(config.wiki.bot_username(), password)?;
+    client.edit(&Edit {
+        title,
+        text,
+        summary: options.summary.as_deref().unwrap_or(DEFAULT_SUMMARY),
+        bot: options.bot,
+        dry_run: options.dry_run,
+    })
+}
+
+/// Print `text`, or upload it if `options.publish` is set. `default_title` is
+/// used if `options.page` isn't set.
+pub fn print_or_publish<T: AsRef<str>>(
+    options: &PublishOptions,
+    default_title: T,
+    text: &str,
+    config: &Config,
+) {
+    if !options.publish {
+        println!("{text}");
+        return;
+    }
+
+    let title = options.page.as_deref().unwrap_or(default_title.as_ref());
+    match upload(options, title, text, config) {
+        Ok(EditResult::Edited { revid }) => println!("Edited {title:?} (revision {revid})."),
+        Ok(EditResult::NoChange) => println!("{title:?} is already up to date."),
+        Ok(EditResult::DryRun) => println!("Dry run: would have edited {title:?}."),
+        Err(e) => panic!("Couldn't publish {title:?}: {e}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::interface::wiki_api::mock_server::{MockResponse, MockServer};
+
+    #[test]
+    fn upload_with_config() {
+        let server = MockServer::start(|request| {
+            match request.param("action").unwrap_or_default().as_str() {
+                "query" => {
+                    MockResponse::json(r#"{"query":{"tokens":{"logintoken":"l","csrftoken":"c"}}}"#)
+                }
+                "login" => MockResponse::json(r#"{"login":{"result":"Success"}}"#),
+                _ => MockResponse::json(r#"{"edit":{"result":"Success","newrevid":7}}"#),
+            }
+        });
+
+        let mut config = Config::default();
+        config.wiki.username = "User".into();
+        config.wiki.bot_password = Some("password".into());
+        config.wiki.api_url = Some(server.url());
+        let options = PublishOptions {
+            publish: true,
+            summary: Some("summary".into()),
+            ..Default::default()
+        };
+
+        let result = upload(&options, "Sandbox", "text", &config).unwrap();
+        assert_eq!(result, EditResult::Edited { revid: 7 });
+
+        let requests = server.requests();
+        let login = requests
+            .iter()
+            .find(|r| r.param("action").as_deref() == Some("login"))
+            .unwrap();
+        assert_eq!(login.param("lgname").unwrap(), "User");
+        let edit = requests.last().unwrap();
+        assert_eq!(edit.param("summary").unwrap(), "summary");
+        assert_eq!(edit.param("bot"), None);
+        assert_eq!(edit.header("user-agent"), Some("User/rust-wiki-reader"));
+    }
+
+    #[test]
+    fn no_password() {
+        let options = PublishOptions::default();
+        let err = upload(&options, "Sandbox", "text", &Config::default()).unwrap_err();
+        assert!(matches!(err, WikiApiError::Login(_)));
+    }
+}
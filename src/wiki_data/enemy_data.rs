@@ -55,6 +55,11 @@ impl EnemyDataContainer {
     pub fn get_common_name(&self, id: u32) -> &str {
         &self.get_data(id).name
     }
+    /// Get the name of the enemy's page on the wiki.
+    pub fn get_page(&self, id: u32) -> &str {
+        let data = self.get_data(id);
+        data.link.as_deref().unwrap_or(&data.name)
+    }
     /// Get the data of the enemy.
     pub fn get_data(&self, id: u32) -> &EnemyData {
         self.data.get(&id).unwrap()
@@ -165,6 +170,12 @@ mod tests {
         assert_eq!(doge.link, None);
     }
 
+    #[test]
+    fn test_page_name() {
+        assert_eq!(ENEMY_DATA.get_page(354), "Hermit Cat (Enemy)");
+        assert_eq!(ENEMY_DATA.get_page(0), "Doge");
+    }
+
     #[test]
     fn test_reverse_map() {
         let id = 0;
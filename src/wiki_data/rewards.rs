@@ -1,6 +1,9 @@
 //! Get information about stage rewards.
 
-use crate::wiki_data::file_handler::get_wiki_data_location;
+use crate::{
+    game_data::{item::gatya_item::GatyaItems, version::Version},
+    wiki_data::file_handler::get_wiki_data_location,
+};
 use serde::Deserialize;
 use std::{collections::HashMap, sync::LazyLock};
 
@@ -18,14 +21,40 @@ pub struct TreasureMap {
     map: LazyLock<MapStructure>,
 }
 impl TreasureMap {
-    fn get_treasure(&self, id: u32) -> &TreasureEntry {
-        self.map
-            .get(&id)
-            .unwrap_or_else(|| panic!("Treasure id not found: {id}."))
+    /// Does Treasures.csv contain the treasure?
+    pub fn has_treasure(&self, id: u32) -> bool {
+        self.map.contains_key(&id)
     }
-    /// Get the name of the treasure.
-    pub fn get_treasure_name(&self, id: u32) -> &str {
-        &self.get_treasure(id).name
+
+    /// Get the name of the treasure. If Treasures.csv doesn't have it then
+    /// uses the item's in-game name from `version`, or `"?"` if the game
+    /// doesn't have it either.
+    pub fn get_treasure_name<'a>(&'a self, id: u32, version: &'a Version) -> &'a str {
+        if let Some(treasure) = self.map.get(&id) {
+            return &treasure.name;
+        }
+
+        match version.get_cached_file::<GatyaItems>().name(id) {
+            Some(name) => {
+                log::warn!("Treasure {id} ({name}) is not in Treasures.csv, using in-game name.");
+                name
+            }
+            None => {
+                log::warn!("Treasure {id} has no name in Treasures.csv or in game.");
+                "?"
+            }
+        }
+    }
+
+    /// Get the id and in-game name of every named item in `items` that
+    /// Treasures.csv doesn't have. Items without an in-game name are
+    /// placeholders and are skipped.
+    pub fn missing_items<'a>(&self, items: &'a GatyaItems) -> Vec<(u32, &'a str)> {
+        items
+            .ids()
+            .filter(|id| !self.has_treasure(*id))
+            .filter_map(|id| Some((id, items.name(id)?)))
+            .collect()
     }
 }
 
@@ -33,7 +62,6 @@ impl TreasureMap {
 pub static TREASURE_DATA: TreasureMap = TreasureMap {
     map: LazyLock::new(get_treasure_data),
 };
-// TODO support Gatyaitembuy.csv
 
 fn get_treasure_data() -> MapStructure {
     let rdr = csv::ReaderBuilder::new()
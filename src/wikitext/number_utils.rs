@@ -28,7 +28,7 @@ pub fn get_precision_f(frames: u32) -> usize {
 /// assert_eq!(plural(1, "thing", "things"), "thing");
 /// assert_eq!(plural(2, "thing", "things"), "things");
 /// ```
-pub fn plural<'a>(amt: u16, single: &'a str, plural: &'a str) -> &'a str {
+pub fn plural<'a>(amt: u32, single: &'a str, plural: &'a str) -> &'a str {
     if amt == 1 { single } else { plural }
 }
 
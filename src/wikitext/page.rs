@@ -1,6 +1,6 @@
 //! Represents the whole wikitext page.
 
-use crate::wikitext::section::Section;
+use crate::wikitext::section::{Section, SectionTitle};
 use std::fmt::Display;
 
 /// Represents a wikitext page.
@@ -23,6 +23,52 @@ impl Page {
     pub fn push(&mut self, section: Section) {
         self.0.push(section);
     }
+
+    /// Get all sections of the page.
+    pub fn sections(&self) -> &[Section] {
+        &self.0
+    }
+}
+
+/// Parse a heading line, e.g. `==Title==`.
+fn parse_heading(line: &str) -> Option<SectionTitle> {
+    let line = line.trim_end();
+    let level = line.chars().take_while(|c| *c == '=').count();
+    let trailing = line.chars().rev().take_while(|c| *c == '=').count();
+    if level != trailing || line.len() <= level * 2 {
+        return None;
+    }
+
+    let title = line[level..line.len() - level].trim().to_string();
+    SectionTitle::from_level(level.try_into().ok()?, title)
+}
+
+impl Page {
+    /// Split existing wikitext into sections.
+    pub fn from_wikitext(text: &str) -> Self {
+        let mut sections = vec![];
+        let mut title = SectionTitle::Blank;
+        let mut content = String::new();
+
+        for line in text.lines() {
+            let Some(heading) = parse_heading(line) else {
+                content += line;
+                content.push('\n');
+                continue;
+            };
+
+            if title != SectionTitle::Blank || !content.trim().is_empty() {
+                sections.push(Section::new(title, content.into()));
+            }
+            title = heading;
+            content = String::new();
+        }
+        if title != SectionTitle::Blank || !content.trim().is_empty() {
+            sections.push(Section::new(title, content.into()));
+        }
+
+        Self::new(sections)
+    }
 }
 
 impl Display for Page {
@@ -40,3 +86,39 @@ impl Display for Page {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn round_trip() {
+        let mut page = Page::blank();
+        page.push(Section::blank("Intro."));
+        page.push(Section::h2("Battlegrounds", "Enemies."));
+        page.push(Section::h3("Effective Stats", "{| table\n|}"));
+        page.push(Section::h2("Strategy", "-"));
+
+        let parsed = Page::from_wikitext(&page.to_string());
+        assert_eq!(parsed.to_string(), page.to_string());
+        assert_eq!(
+            parsed.sections()[2].title,
+            SectionTitle::H3("Effective Stats".into())
+        );
+    }
+
+    #[test]
+    fn headings() {
+        assert_eq!(
+            parse_heading("== Title =="),
+            Some(SectionTitle::H2("Title".into()))
+        );
+        assert_eq!(
+            parse_heading("====h4===="),
+            Some(SectionTitle::H4("h4".into()))
+        );
+        assert_eq!(parse_heading("==unbalanced==="), None);
+        assert_eq!(parse_heading("===="), None);
+        assert_eq!(parse_heading("{{Template}}"), None);
+    }
+}
@@ -20,6 +20,42 @@ pub enum SectionTitle {
     H6(StringValue),
 }
 
+impl SectionTitle {
+    /// Heading level of the title (`==h2==` = 2). Blank is 0.
+    pub const fn level(&self) -> u8 {
+        match self {
+            Self::Blank => 0,
+            Self::H2(_) => 2,
+            Self::H3(_) => 3,
+            Self::H4(_) => 4,
+            Self::H5(_) => 5,
+            Self::H6(_) => 6,
+        }
+    }
+
+    /// Text of the title. Blank is empty.
+    pub fn text(&self) -> &str {
+        match self {
+            Self::Blank => "",
+            Self::H2(t) | Self::H3(t) | Self::H4(t) | Self::H5(t) | Self::H6(t) => t,
+        }
+    }
+
+    /// Create title from heading `level` and `title`. Returns [`None`] if
+    /// level is invalid.
+    pub fn from_level<T: Into<StringValue>>(level: u8, title: T) -> Option<Self> {
+        let title = title.into();
+        Some(match level {
+            2 => Self::H2(title),
+            3 => Self::H3(title),
+            4 => Self::H4(title),
+            5 => Self::H5(title),
+            6 => Self::H6(title),
+            _ => return None,
+        })
+    }
+}
+
 /// Page section.
 #[derive(Debug, PartialEq, Eq)]
 pub struct Section {